@@ -15,6 +15,7 @@ export const LOCALE_CATEGORY = {
     HEADING_GAPS: "headingGaps",
     OTHER_GAPS: "otherGaps",
     FORMAT_OPTIONS: "formatOptions",
+    PROPERTY_OPTIONS: "propertyOptions",
     OTHER_OPTIONS: "otherOptions",
 } as const;
 
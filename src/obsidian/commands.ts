@@ -27,6 +27,17 @@ export class FormattoCommands {
                     this.plugin.utils.formatDocument(editor);
                 },
             },
+            {
+                id: "format-selected-lines",
+                name: getLocale(
+                    LOCALE_CATEGORY.COMMANDS,
+                    "Format Selected Lines"
+                ),
+                icon: "formatto-logo",
+                editorCallback: (editor) => {
+                    this.plugin.utils.formatSelectedLines(editor);
+                },
+            },
         ];
     }
 }
@@ -6,6 +6,13 @@ import { FALLBACK_OPTIONS } from "./optionTypes";
 
 import type { App } from "obsidian";
 import type FormattoPlugin from "@src/main";
+import type {
+    HeadingStyle,
+    PropertyKeyOrder,
+    PropertyListStyle,
+    PropertyQuoteStyle,
+    ThematicBreakStyle,
+} from "./optionTypes";
 
 export class FormattoOptionTab extends PluginSettingTab {
     private plugin: FormattoPlugin;
@@ -305,6 +312,432 @@ export class FormattoOptionTab extends PluginSettingTab {
                         await this.plugin.saveOptions();
                     })
             );
+        new Setting(containerEl)
+            .setName(
+                getLocale(
+                    LOCALE_CATEGORY.OTHER_GAPS,
+                    "Before contents after math blocks"
+                )
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.OTHER_GAPS,
+                    "Decides gaps before 'contents that are after math blocks.'"
+                )
+            )
+            .addText((text) =>
+                text
+                    .setPlaceholder(
+                        this.putDefaultIndicator(
+                            FALLBACK_OPTIONS.otherGaps
+                                .beforeContentsAfterMathBlocks
+                        )
+                    )
+                    .setValue(
+                        this.plugin.settings.otherGaps
+                            .beforeContentsAfterMathBlocks
+                    )
+                    .onChange(async (value) => {
+                        debounceMsg(value);
+
+                        this.plugin.settings.otherGaps.beforeContentsAfterMathBlocks =
+                            value;
+                        await this.plugin.saveOptions();
+                    })
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(LOCALE_CATEGORY.OTHER_GAPS, "Before math blocks")
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.OTHER_GAPS,
+                    "Decides gaps before math blocks."
+                )
+            )
+            .addText((text) =>
+                text
+                    .setPlaceholder(
+                        this.putDefaultIndicator(
+                            FALLBACK_OPTIONS.otherGaps.beforeMathBlocks
+                        )
+                    )
+                    .setValue(this.plugin.settings.otherGaps.beforeMathBlocks)
+                    .onChange(async (value) => {
+                        debounceMsg(value);
+
+                        this.plugin.settings.otherGaps.beforeMathBlocks = value;
+                        await this.plugin.saveOptions();
+                    })
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(
+                    LOCALE_CATEGORY.OTHER_GAPS,
+                    "Before math blocks after headings"
+                )
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.OTHER_GAPS,
+                    "Decides gaps before 'math blocks that are after headings.'"
+                )
+            )
+            .addText((text) =>
+                text
+                    .setPlaceholder(
+                        this.putDefaultIndicator(
+                            FALLBACK_OPTIONS.otherGaps
+                                .beforeMathBlocksAfterHeadings
+                        )
+                    )
+                    .setValue(
+                        this.plugin.settings.otherGaps
+                            .beforeMathBlocksAfterHeadings
+                    )
+                    .onChange(async (value) => {
+                        debounceMsg(value);
+
+                        this.plugin.settings.otherGaps.beforeMathBlocksAfterHeadings =
+                            value;
+                        await this.plugin.saveOptions();
+                    })
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(
+                    LOCALE_CATEGORY.OTHER_GAPS,
+                    "Before contents after HTML blocks"
+                )
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.OTHER_GAPS,
+                    "Decides gaps before 'contents that are after HTML blocks.'"
+                )
+            )
+            .addText((text) =>
+                text
+                    .setPlaceholder(
+                        this.putDefaultIndicator(
+                            FALLBACK_OPTIONS.otherGaps
+                                .beforeContentsAfterHtmlBlocks
+                        )
+                    )
+                    .setValue(
+                        this.plugin.settings.otherGaps
+                            .beforeContentsAfterHtmlBlocks
+                    )
+                    .onChange(async (value) => {
+                        debounceMsg(value);
+
+                        this.plugin.settings.otherGaps.beforeContentsAfterHtmlBlocks =
+                            value;
+                        await this.plugin.saveOptions();
+                    })
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(LOCALE_CATEGORY.OTHER_GAPS, "Before HTML blocks")
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.OTHER_GAPS,
+                    "Decides gaps before HTML blocks."
+                )
+            )
+            .addText((text) =>
+                text
+                    .setPlaceholder(
+                        this.putDefaultIndicator(
+                            FALLBACK_OPTIONS.otherGaps.beforeHtmlBlocks
+                        )
+                    )
+                    .setValue(this.plugin.settings.otherGaps.beforeHtmlBlocks)
+                    .onChange(async (value) => {
+                        debounceMsg(value);
+
+                        this.plugin.settings.otherGaps.beforeHtmlBlocks = value;
+                        await this.plugin.saveOptions();
+                    })
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(
+                    LOCALE_CATEGORY.OTHER_GAPS,
+                    "Before HTML blocks after headings"
+                )
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.OTHER_GAPS,
+                    "Decides gaps before 'HTML blocks that are after headings.'"
+                )
+            )
+            .addText((text) =>
+                text
+                    .setPlaceholder(
+                        this.putDefaultIndicator(
+                            FALLBACK_OPTIONS.otherGaps
+                                .beforeHtmlBlocksAfterHeadings
+                        )
+                    )
+                    .setValue(
+                        this.plugin.settings.otherGaps
+                            .beforeHtmlBlocksAfterHeadings
+                    )
+                    .onChange(async (value) => {
+                        debounceMsg(value);
+
+                        this.plugin.settings.otherGaps.beforeHtmlBlocksAfterHeadings =
+                            value;
+                        await this.plugin.saveOptions();
+                    })
+            );
+        new Setting(containerEl)
+            .setName(getLocale(LOCALE_CATEGORY.OTHER_GAPS, "Before callouts"))
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.OTHER_GAPS,
+                    "Decides gaps before callouts."
+                )
+            )
+            .addText((text) =>
+                text
+                    .setPlaceholder(
+                        this.putDefaultIndicator(
+                            FALLBACK_OPTIONS.otherGaps.beforeCallouts
+                        )
+                    )
+                    .setValue(this.plugin.settings.otherGaps.beforeCallouts)
+                    .onChange(async (value) => {
+                        debounceMsg(value);
+
+                        this.plugin.settings.otherGaps.beforeCallouts = value;
+                        await this.plugin.saveOptions();
+                    })
+            );
+        new Setting(containerEl)
+            .setName(getLocale(LOCALE_CATEGORY.OTHER_GAPS, "After callouts"))
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.OTHER_GAPS,
+                    "Decides gaps after callouts."
+                )
+            )
+            .addText((text) =>
+                text
+                    .setPlaceholder(
+                        this.putDefaultIndicator(
+                            FALLBACK_OPTIONS.otherGaps.afterCallouts
+                        )
+                    )
+                    .setValue(this.plugin.settings.otherGaps.afterCallouts)
+                    .onChange(async (value) => {
+                        debounceMsg(value);
+
+                        this.plugin.settings.otherGaps.afterCallouts = value;
+                        await this.plugin.saveOptions();
+                    })
+            );
+        new Setting(containerEl)
+            .setName(getLocale(LOCALE_CATEGORY.OTHER_GAPS, "Before lists"))
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.OTHER_GAPS,
+                    "Decides gaps before lists."
+                )
+            )
+            .addText((text) =>
+                text
+                    .setPlaceholder(
+                        this.putDefaultIndicator(
+                            FALLBACK_OPTIONS.otherGaps.beforeLists
+                        )
+                    )
+                    .setValue(this.plugin.settings.otherGaps.beforeLists)
+                    .onChange(async (value) => {
+                        debounceMsg(value);
+
+                        this.plugin.settings.otherGaps.beforeLists = value;
+                        await this.plugin.saveOptions();
+                    })
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(
+                    LOCALE_CATEGORY.OTHER_GAPS,
+                    "Before lists after headings"
+                )
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.OTHER_GAPS,
+                    "Decides gaps before 'lists that are after headings.'"
+                )
+            )
+            .addText((text) =>
+                text
+                    .setPlaceholder(
+                        this.putDefaultIndicator(
+                            FALLBACK_OPTIONS.otherGaps.beforeListsAfterHeadings
+                        )
+                    )
+                    .setValue(
+                        this.plugin.settings.otherGaps.beforeListsAfterHeadings
+                    )
+                    .onChange(async (value) => {
+                        debounceMsg(value);
+
+                        this.plugin.settings.otherGaps.beforeListsAfterHeadings =
+                            value;
+                        await this.plugin.saveOptions();
+                    })
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(
+                    LOCALE_CATEGORY.OTHER_GAPS,
+                    "Before contents after lists"
+                )
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.OTHER_GAPS,
+                    "Decides gaps before 'contents that are after lists.'"
+                )
+            )
+            .addText((text) =>
+                text
+                    .setPlaceholder(
+                        this.putDefaultIndicator(
+                            FALLBACK_OPTIONS.otherGaps.beforeContentsAfterLists
+                        )
+                    )
+                    .setValue(
+                        this.plugin.settings.otherGaps.beforeContentsAfterLists
+                    )
+                    .onChange(async (value) => {
+                        debounceMsg(value);
+
+                        this.plugin.settings.otherGaps.beforeContentsAfterLists =
+                            value;
+                        await this.plugin.saveOptions();
+                    })
+            );
+        new Setting(containerEl)
+            .setName(getLocale(LOCALE_CATEGORY.OTHER_GAPS, "Before tables"))
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.OTHER_GAPS,
+                    "Decides gaps before tables."
+                )
+            )
+            .addText((text) =>
+                text
+                    .setPlaceholder(
+                        this.putDefaultIndicator(
+                            FALLBACK_OPTIONS.otherGaps.beforeTables
+                        )
+                    )
+                    .setValue(this.plugin.settings.otherGaps.beforeTables)
+                    .onChange(async (value) => {
+                        debounceMsg(value);
+
+                        this.plugin.settings.otherGaps.beforeTables = value;
+                        await this.plugin.saveOptions();
+                    })
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(
+                    LOCALE_CATEGORY.OTHER_GAPS,
+                    "Before tables after headings"
+                )
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.OTHER_GAPS,
+                    "Decides gaps before 'tables that are after headings.'"
+                )
+            )
+            .addText((text) =>
+                text
+                    .setPlaceholder(
+                        this.putDefaultIndicator(
+                            FALLBACK_OPTIONS.otherGaps.beforeTablesAfterHeadings
+                        )
+                    )
+                    .setValue(
+                        this.plugin.settings.otherGaps.beforeTablesAfterHeadings
+                    )
+                    .onChange(async (value) => {
+                        debounceMsg(value);
+
+                        this.plugin.settings.otherGaps.beforeTablesAfterHeadings =
+                            value;
+                        await this.plugin.saveOptions();
+                    })
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(
+                    LOCALE_CATEGORY.OTHER_GAPS,
+                    "Before contents after tables"
+                )
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.OTHER_GAPS,
+                    "Decides gaps before 'contents that are after tables.'"
+                )
+            )
+            .addText((text) =>
+                text
+                    .setPlaceholder(
+                        this.putDefaultIndicator(
+                            FALLBACK_OPTIONS.otherGaps.beforeContentsAfterTables
+                        )
+                    )
+                    .setValue(
+                        this.plugin.settings.otherGaps.beforeContentsAfterTables
+                    )
+                    .onChange(async (value) => {
+                        debounceMsg(value);
+
+                        this.plugin.settings.otherGaps.beforeContentsAfterTables =
+                            value;
+                        await this.plugin.saveOptions();
+                    })
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(LOCALE_CATEGORY.OTHER_GAPS, "Around horizontal rules")
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.OTHER_GAPS,
+                    "Decides gaps before and after horizontal rules."
+                )
+            )
+            .addText((text) =>
+                text
+                    .setPlaceholder(
+                        this.putDefaultIndicator(
+                            FALLBACK_OPTIONS.otherGaps.aroundHorizontalRules
+                        )
+                    )
+                    .setValue(
+                        this.plugin.settings.otherGaps.aroundHorizontalRules
+                    )
+                    .onChange(async (value) => {
+                        debounceMsg(value);
+
+                        this.plugin.settings.otherGaps.aroundHorizontalRules =
+                            value;
+                        await this.plugin.saveOptions();
+                    })
+            );
 
         // Format Options
         containerEl.createEl("h2", {
@@ -332,6 +765,370 @@ export class FormattoOptionTab extends PluginSettingTab {
                         await this.plugin.saveOptions();
                     })
             );
+        new Setting(containerEl)
+            .setName(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Thematic break style"
+                )
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Decides which characters are used for thematic breaks."
+                )
+            )
+            .addDropdown((dropdown) =>
+                dropdown
+                    .addOptions({
+                        preserve: getLocale(
+                            LOCALE_CATEGORY.FORMAT_OPTIONS,
+                            "Preserve"
+                        ),
+                        asterisks: "***",
+                        underscores: "___",
+                    })
+                    .setValue(
+                        this.plugin.settings.formatOptions.thematicBreakStyle
+                    )
+                    .onChange(async (value) => {
+                        this.plugin.settings.formatOptions.thematicBreakStyle =
+                            value as ThematicBreakStyle;
+                        await this.plugin.saveOptions();
+                    })
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(LOCALE_CATEGORY.FORMAT_OPTIONS, "Heading style")
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Decides whether headings are written with hashes or underlines."
+                )
+            )
+            .addDropdown((dropdown) =>
+                dropdown
+                    .addOptions({
+                        preserve: getLocale(
+                            LOCALE_CATEGORY.FORMAT_OPTIONS,
+                            "Preserve"
+                        ),
+                        atx: getLocale(
+                            LOCALE_CATEGORY.FORMAT_OPTIONS,
+                            "Hashes"
+                        ),
+                        setext: getLocale(
+                            LOCALE_CATEGORY.FORMAT_OPTIONS,
+                            "Underlines"
+                        ),
+                    })
+                    .setValue(this.plugin.settings.formatOptions.headingStyle)
+                    .onChange(async (value) => {
+                        this.plugin.settings.formatOptions.headingStyle =
+                            value as HeadingStyle;
+                        await this.plugin.saveOptions();
+                    })
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Normalize heading syntax"
+                )
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Writes one space after hashes, and removes closing hashes and trailing spaces of headings. Lines that start with hashes without a space are read as headings too, including tags."
+                )
+            )
+            .addToggle((text) =>
+                text
+                    .setValue(
+                        this.plugin.settings.formatOptions
+                            .normalizeHeadingSyntax
+                    )
+                    .onChange(async (value) => {
+                        this.plugin.settings.formatOptions.normalizeHeadingSyntax =
+                            value;
+                        await this.plugin.saveOptions();
+                    })
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Remove heading indentation"
+                )
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Removes spaces before headings."
+                )
+            )
+            .addToggle((text) =>
+                text
+                    .setValue(
+                        this.plugin.settings.formatOptions
+                            .removeHeadingIndentation
+                    )
+                    .onChange(async (value) => {
+                        this.plugin.settings.formatOptions.removeHeadingIndentation =
+                            value;
+                        await this.plugin.saveOptions();
+                    })
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Fix skipped heading levels"
+                )
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Renumbers headings, so that their levels do not skip."
+                )
+            )
+            .addToggle((text) =>
+                text
+                    .setValue(
+                        this.plugin.settings.formatOptions
+                            .fixSkippedHeadingLevels
+                    )
+                    .onChange(async (value) => {
+                        this.plugin.settings.formatOptions.fixSkippedHeadingLevels =
+                            value;
+                        await this.plugin.saveOptions();
+                    })
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Demote extra top level headings"
+                )
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Demotes top level headings after the first one, with the headings under them. Nothing is demoted if a level 6 heading would have to be."
+                )
+            )
+            .addToggle((text) =>
+                text
+                    .setValue(
+                        this.plugin.settings.formatOptions
+                            .demoteExtraTopLevelHeadings
+                    )
+                    .onChange(async (value) => {
+                        this.plugin.settings.formatOptions.demoteExtraTopLevelHeadings =
+                            value;
+                        await this.plugin.saveOptions();
+                    })
+            );
+
+        // Property Options
+        containerEl.createEl("h2", {
+            text: getLocale(
+                LOCALE_CATEGORY.OPTION_SECTIONS,
+                "Property options"
+            ),
+        });
+        new Setting(containerEl)
+            .setName(getLocale(LOCALE_CATEGORY.PROPERTY_OPTIONS, "Key order"))
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.PROPERTY_OPTIONS,
+                    "Decides the order of property keys."
+                )
+            )
+            .addDropdown((dropdown) =>
+                dropdown
+                    .addOptions({
+                        preserve: getLocale(
+                            LOCALE_CATEGORY.PROPERTY_OPTIONS,
+                            "Preserve"
+                        ),
+                        alphabetical: getLocale(
+                            LOCALE_CATEGORY.PROPERTY_OPTIONS,
+                            "Alphabetical"
+                        ),
+                    })
+                    .setValue(this.plugin.settings.propertyOptions.keyOrder)
+                    .onChange(async (value) => {
+                        this.plugin.settings.propertyOptions.keyOrder =
+                            value as PropertyKeyOrder;
+                        await this.plugin.saveOptions();
+                    })
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(LOCALE_CATEGORY.PROPERTY_OPTIONS, "Priority keys")
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.PROPERTY_OPTIONS,
+                    "Comma separated keys that come before other keys."
+                )
+            )
+            .addText((text) =>
+                text
+                    .setPlaceholder("title, aliases, tags")
+                    .setValue(this.plugin.settings.propertyOptions.priorityKeys)
+                    .onChange(async (value) => {
+                        this.plugin.settings.propertyOptions.priorityKeys =
+                            value;
+                        await this.plugin.saveOptions();
+                    })
+            );
+        new Setting(containerEl)
+            .setName(getLocale(LOCALE_CATEGORY.PROPERTY_OPTIONS, "List style"))
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.PROPERTY_OPTIONS,
+                    "Decides how lists are written."
+                )
+            )
+            .addDropdown((dropdown) =>
+                dropdown
+                    .addOptions({
+                        preserve: getLocale(
+                            LOCALE_CATEGORY.PROPERTY_OPTIONS,
+                            "Preserve"
+                        ),
+                        flow: "[a, b]",
+                        block: "- a",
+                    })
+                    .setValue(this.plugin.settings.propertyOptions.listStyle)
+                    .onChange(async (value) => {
+                        this.plugin.settings.propertyOptions.listStyle =
+                            value as PropertyListStyle;
+                        await this.plugin.saveOptions();
+                    })
+            );
+        new Setting(containerEl)
+            .setName(getLocale(LOCALE_CATEGORY.PROPERTY_OPTIONS, "Quote style"))
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.PROPERTY_OPTIONS,
+                    "Decides which quotes are used for quoted values."
+                )
+            )
+            .addDropdown((dropdown) =>
+                dropdown
+                    .addOptions({
+                        preserve: getLocale(
+                            LOCALE_CATEGORY.PROPERTY_OPTIONS,
+                            "Preserve"
+                        ),
+                        double: '"',
+                        single: "'",
+                    })
+                    .setValue(this.plugin.settings.propertyOptions.quoteStyle)
+                    .onChange(async (value) => {
+                        this.plugin.settings.propertyOptions.quoteStyle =
+                            value as PropertyQuoteStyle;
+                        await this.plugin.saveOptions();
+                    })
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(
+                    LOCALE_CATEGORY.PROPERTY_OPTIONS,
+                    "Remove blank lines"
+                )
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.PROPERTY_OPTIONS,
+                    "Removes blank lines between properties."
+                )
+            )
+            .addToggle((text) =>
+                text
+                    .setValue(
+                        this.plugin.settings.propertyOptions.removeBlankLines
+                    )
+                    .onChange(async (value) => {
+                        this.plugin.settings.propertyOptions.removeBlankLines =
+                            value;
+                        await this.plugin.saveOptions();
+                    })
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(
+                    LOCALE_CATEGORY.PROPERTY_OPTIONS,
+                    "Normalize list properties"
+                )
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.PROPERTY_OPTIONS,
+                    "Writes tags, aliases and cssclasses as lists without duplicates."
+                )
+            )
+            .addToggle((text) =>
+                text
+                    .setValue(
+                        this.plugin.settings.propertyOptions
+                            .normalizeListProperties
+                    )
+                    .onChange(async (value) => {
+                        this.plugin.settings.propertyOptions.normalizeListProperties =
+                            value;
+                        await this.plugin.saveOptions();
+                    })
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(LOCALE_CATEGORY.PROPERTY_OPTIONS, "Lowercase tags")
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.PROPERTY_OPTIONS,
+                    "Writes tags in lowercase, when list properties are normalized."
+                )
+            )
+            .addToggle((text) =>
+                text
+                    .setValue(
+                        this.plugin.settings.propertyOptions.lowercaseTags
+                    )
+                    .onChange(async (value) => {
+                        this.plugin.settings.propertyOptions.lowercaseTags =
+                            value;
+                        await this.plugin.saveOptions();
+                    })
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(
+                    LOCALE_CATEGORY.PROPERTY_OPTIONS,
+                    "Sort list properties"
+                )
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.PROPERTY_OPTIONS,
+                    "Sorts values of list properties, when they are normalized."
+                )
+            )
+            .addToggle((text) =>
+                text
+                    .setValue(
+                        this.plugin.settings.propertyOptions.sortListProperties
+                    )
+                    .onChange(async (value) => {
+                        this.plugin.settings.propertyOptions.sortListProperties =
+                            value;
+                        await this.plugin.saveOptions();
+                    })
+            );
 
         // Other Options
         containerEl.createEl("h2", {
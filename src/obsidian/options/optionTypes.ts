@@ -22,11 +22,79 @@ export interface OtherGaps {
     beforeCodeBlocks: string;
     /** Decides gaps before 'code blocks that are after headings.' */
     beforeCodeBlocksAfterHeadings: string;
+    /** Decides gaps before 'contents that are after math blocks.' */
+    beforeContentsAfterMathBlocks: string;
+    /** Decides gaps before math blocks. */
+    beforeMathBlocks: string;
+    /** Decides gaps before 'math blocks that are after headings.' */
+    beforeMathBlocksAfterHeadings: string;
+    /** Decides gaps before 'contents that are after HTML blocks.' */
+    beforeContentsAfterHtmlBlocks: string;
+    /** Decides gaps before HTML blocks. */
+    beforeHtmlBlocks: string;
+    /** Decides gaps before 'HTML blocks that are after headings.' */
+    beforeHtmlBlocksAfterHeadings: string;
+    /** Decides gaps before callouts. */
+    beforeCallouts: string;
+    /** Decides gaps after callouts. */
+    afterCallouts: string;
+    /** Decides gaps before lists. */
+    beforeLists: string;
+    /** Decides gaps before 'lists that are after headings.' */
+    beforeListsAfterHeadings: string;
+    /** Decides gaps before 'contents that are after lists.' */
+    beforeContentsAfterLists: string;
+    /** Decides gaps before tables. */
+    beforeTables: string;
+    /** Decides gaps before 'tables that are after headings.' */
+    beforeTablesAfterHeadings: string;
+    /** Decides gaps before 'contents that are after tables.' */
+    beforeContentsAfterTables: string;
+    /** Decides gaps before and after horizontal rules. */
+    aroundHorizontalRules: string;
 }
 
+export type ThematicBreakStyle = "preserve" | "asterisks" | "underscores";
+export type HeadingStyle = "preserve" | "atx" | "setext";
+
 export interface FormatOptions {
     /** Inserts a newline at the end of a document. */
     insertNewline: boolean;
+    /** Decides which characters are used for thematic breaks. */
+    thematicBreakStyle: ThematicBreakStyle;
+    /** Decides whether headings are written with hashes or underlines. */
+    headingStyle: HeadingStyle;
+    /** Writes one space after hashes, and removes closing hashes and trailing spaces of headings. Lines that start with hashes without a space are read as headings too, including tags. */
+    normalizeHeadingSyntax: boolean;
+    /** Removes spaces before headings. */
+    removeHeadingIndentation: boolean;
+    /** Renumbers headings, so that their levels do not skip. */
+    fixSkippedHeadingLevels: boolean;
+    /** Demotes top level headings after the first one, with the headings under them. Nothing is demoted if a level 6 heading would have to be. */
+    demoteExtraTopLevelHeadings: boolean;
+}
+
+export type PropertyKeyOrder = "preserve" | "alphabetical";
+export type PropertyListStyle = "preserve" | "flow" | "block";
+export type PropertyQuoteStyle = "preserve" | "double" | "single";
+
+export interface PropertyOptions {
+    /** Decides the order of property keys. */
+    keyOrder: PropertyKeyOrder;
+    /** Comma separated keys that come before other keys. */
+    priorityKeys: string;
+    /** Decides how lists are written. */
+    listStyle: PropertyListStyle;
+    /** Decides which quotes are used for quoted values. */
+    quoteStyle: PropertyQuoteStyle;
+    /** Removes blank lines between properties. */
+    removeBlankLines: boolean;
+    /** Writes tags, aliases and cssclasses as lists without duplicates. */
+    normalizeListProperties: boolean;
+    /** Writes tags in lowercase, when list properties are normalized. */
+    lowercaseTags: boolean;
+    /** Sorts values of list properties, when they are normalized. */
+    sortListProperties: boolean;
 }
 
 export interface OtherOptions {
@@ -40,6 +108,7 @@ export interface FormattoPluginOptions {
     headingGaps: Partial<HeadingGaps>;
     otherGaps: Partial<OtherGaps>;
     formatOptions: Partial<FormatOptions>;
+    propertyOptions: Partial<PropertyOptions>;
     otherOptions: Partial<OtherOptions>;
 }
 
@@ -59,10 +128,42 @@ export const FALLBACK_OTHER_GAPS: Partial<OtherGaps> = {
     beforeContentsAfterCodeBlocks: "1",
     beforeCodeBlocks: "1",
     beforeCodeBlocksAfterHeadings: "0",
+    beforeContentsAfterMathBlocks: "1",
+    beforeMathBlocks: "1",
+    beforeMathBlocksAfterHeadings: "0",
+    beforeContentsAfterHtmlBlocks: "1",
+    beforeHtmlBlocks: "1",
+    beforeHtmlBlocksAfterHeadings: "0",
+    beforeCallouts: "1",
+    afterCallouts: "1",
+    beforeLists: "0",
+    beforeListsAfterHeadings: "0",
+    beforeContentsAfterLists: "1",
+    beforeTables: "0",
+    beforeTablesAfterHeadings: "0",
+    beforeContentsAfterTables: "1",
+    aroundHorizontalRules: "1",
 };
 
 export const FALLBACK_FORMAT_OPTIONS: Partial<FormatOptions> = {
     insertNewline: true,
+    thematicBreakStyle: "preserve",
+    headingStyle: "preserve",
+    normalizeHeadingSyntax: false,
+    removeHeadingIndentation: false,
+    fixSkippedHeadingLevels: false,
+    demoteExtraTopLevelHeadings: false,
+};
+
+export const FALLBACK_PROPERTY_OPTIONS: Partial<PropertyOptions> = {
+    keyOrder: "preserve",
+    priorityKeys: "",
+    listStyle: "preserve",
+    quoteStyle: "preserve",
+    removeBlankLines: false,
+    normalizeListProperties: false,
+    lowercaseTags: false,
+    sortListProperties: false,
 };
 
 export const FALLBACK_OTHER_OPTIONS: Partial<OtherOptions> = {
@@ -74,6 +175,7 @@ export const FALLBACK_OPTIONS: FormattoPluginOptions = {
     headingGaps: FALLBACK_HEADING_GAPS,
     otherGaps: FALLBACK_OTHER_GAPS,
     formatOptions: FALLBACK_FORMAT_OPTIONS,
+    propertyOptions: FALLBACK_PROPERTY_OPTIONS,
     otherOptions: FALLBACK_OTHER_OPTIONS,
 };
 
@@ -93,11 +195,27 @@ export const EMPTY_OTHER_GAPS: Partial<OtherGaps> = {
     beforeContentsAfterCodeBlocks: "",
     beforeCodeBlocks: "",
     beforeCodeBlocksAfterHeadings: "",
+    beforeContentsAfterMathBlocks: "",
+    beforeMathBlocks: "",
+    beforeMathBlocksAfterHeadings: "",
+    beforeContentsAfterHtmlBlocks: "",
+    beforeHtmlBlocks: "",
+    beforeHtmlBlocksAfterHeadings: "",
+    beforeCallouts: "",
+    afterCallouts: "",
+    beforeLists: "",
+    beforeListsAfterHeadings: "",
+    beforeContentsAfterLists: "",
+    beforeTables: "",
+    beforeTablesAfterHeadings: "",
+    beforeContentsAfterTables: "",
+    aroundHorizontalRules: "",
 };
 
 export const DEFAULT_OPTIONS: FormattoPluginOptions = {
     headingGaps: EMPTY_HEADING_GAPS,
     otherGaps: EMPTY_OTHER_GAPS,
     formatOptions: FALLBACK_FORMAT_OPTIONS,
+    propertyOptions: FALLBACK_PROPERTY_OPTIONS,
     otherOptions: FALLBACK_OTHER_OPTIONS,
 };
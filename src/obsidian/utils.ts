@@ -1,16 +1,33 @@
-import { Editor, EditorPosition, Notice } from "obsidian";
+import {
+    Editor,
+    EditorChange,
+    EditorPosition,
+    EditorSelection,
+    Notice,
+} from "obsidian";
 
 import { getLocale, getWasmLocale, LOCALE_CATEGORY } from "@src/lang/lang";
 import FormattoPlugin from "@src/main";
 
-import { format_document } from "../../wasm/pkg/formatto_wasm";
+import {
+    format_document_edits,
+    format_document_range_edits,
+    map_document_positions,
+} from "../../wasm/pkg/formatto_wasm";
 import { FALLBACK_OPTIONS, FormattoPluginOptions } from "./options/optionTypes";
 
+/** Replaces lines `startLine` to `endLine` (exclusive) of a document. */
+interface TextEdit {
+    startLine: number;
+    endLine: number;
+    replacement: string;
+}
+
 export class FormattoUtils {
     private plugin: FormattoPlugin;
-    private cursorPosition: EditorPosition;
     private originalDocument: string;
-    private formattedDocument: string;
+    private textEdits: TextEdit[];
+    private selections: EditorSelection[];
 
     constructor(plugin: FormattoPlugin) {
         this.plugin = plugin;
@@ -20,24 +37,68 @@ export class FormattoUtils {
         const copiedOptions = JSON.parse(JSON.stringify(this.plugin.settings));
         this.handleEmptyOptions(copiedOptions);
 
-        this.cursorPosition = editor.getCursor();
         this.originalDocument = editor.getValue();
 
         try {
-            this.formattedDocument = format_document(
+            this.textEdits = format_document_edits(
                 this.originalDocument,
                 copiedOptions,
                 JSON.stringify(getWasmLocale())
             );
+            this.selections = this.getMappedSelections(
+                editor,
+                copiedOptions
+            );
+            this.displayMessage();
+        } catch (error) {
+            new Notice(error);
+        }
+
+        if (!this.textEdits) return;
+        if (this.textEdits.length > 0) {
+            // Only changed lines are replaced,
+            // so the editor keeps undo history and folds.
+            editor.transaction({
+                changes: this.textEdits.map((edit) =>
+                    this.getEditorChange(editor, edit)
+                ),
+                selections: this.selections.map((selection) => ({
+                    from: selection.anchor,
+                    to: selection.head,
+                })),
+            });
+        }
+
+        this.clearVariables();
+    }
+
+    /** Formats only the sections in the selected lines. */
+    formatSelectedLines(editor: Editor) {
+        const copiedOptions = JSON.parse(JSON.stringify(this.plugin.settings));
+        this.handleEmptyOptions(copiedOptions);
+
+        this.originalDocument = editor.getValue();
+
+        try {
+            this.textEdits = format_document_range_edits(
+                this.originalDocument,
+                copiedOptions,
+                JSON.stringify(getWasmLocale()),
+                editor.getCursor("from").line,
+                editor.getCursor("to").line + 1
+            );
             this.displayMessage();
         } catch (error) {
             new Notice(error);
         }
 
-        if (!this.formattedDocument) return;
-        if (this.formattedDocument !== this.originalDocument) {
-            editor.setValue(this.formattedDocument);
-            editor.setSelection(this.cursorPosition, this.cursorPosition);
+        if (!this.textEdits) return;
+        if (this.textEdits.length > 0) {
+            editor.transaction({
+                changes: this.textEdits.map((edit) =>
+                    this.getEditorChange(editor, edit)
+                ),
+            });
         }
 
         this.clearVariables();
@@ -46,7 +107,7 @@ export class FormattoUtils {
     private displayMessage() {
         if (
             this.plugin.settings.otherOptions.notifyWhenUnchanged &&
-            this.formattedDocument === this.originalDocument
+            this.textEdits.length === 0
         ) {
             new Notice(
                 getLocale(
@@ -64,6 +125,39 @@ export class FormattoUtils {
         }
     }
 
+    /** Returns where the current selections end up after formatting. */
+    private getMappedSelections(
+        editor: Editor,
+        copiedOptions: FormattoPluginOptions
+    ): EditorSelection[] {
+        const selections = editor.listSelections();
+        const positions: EditorPosition[] = map_document_positions(
+            this.originalDocument,
+            copiedOptions,
+            JSON.stringify(getWasmLocale()),
+            selections.flatMap((selection) => [selection.anchor, selection.head])
+        );
+
+        return selections.map((_, index) => ({
+            anchor: positions[index * 2],
+            head: positions[index * 2 + 1],
+        }));
+    }
+
+    private getEditorChange(editor: Editor, edit: TextEdit): EditorChange {
+        const lastLine = editor.lastLine();
+        const to: EditorPosition =
+            edit.endLine <= lastLine
+                ? { line: edit.endLine, ch: 0 }
+                : { line: lastLine, ch: editor.getLine(lastLine).length };
+
+        return {
+            from: { line: edit.startLine, ch: 0 },
+            to,
+            text: edit.replacement,
+        };
+    }
+
     private handleEmptyOptions(copiedOptions: FormattoPluginOptions) {
         for (const sectionKey of Object.keys(copiedOptions)) {
             for (const optionKey of Object.keys(copiedOptions[sectionKey])) {
@@ -76,8 +170,8 @@ export class FormattoUtils {
     }
 
     private clearVariables() {
-        this.cursorPosition = undefined;
         this.originalDocument = undefined;
-        this.formattedDocument = undefined;
+        this.textEdits = undefined;
+        this.selections = undefined;
     }
 }
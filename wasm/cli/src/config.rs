@@ -0,0 +1,54 @@
+use serde_json::Value;
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+use formatto_core::PluginOptions;
+
+/// Reads plugin options from a JSON file.
+///
+/// The file has the same shape as the plugin's `data.json`,
+/// so the options of a vault can be used as they are.
+/// Missing and empty values fall back to the default options.
+pub fn read_options(path: Option<&Path>) -> Result<PluginOptions, Box<dyn Error>> {
+    let path = match path {
+        Some(path) => path,
+        None => return Ok(PluginOptions::default()),
+    };
+
+    let file = fs::read_to_string(path).map_err(|e| {
+        format!(
+            "Failed to read the config file. ({}): {}",
+            path.display(),
+            e
+        )
+    })?;
+
+    parse_options(&file).map_err(|e| {
+        format!(
+            "Failed to read the config file. ({}): {}",
+            path.display(),
+            e
+        )
+        .into()
+    })
+}
+
+/// Parses plugin options from a JSON string.
+pub fn parse_options(input: &str) -> Result<PluginOptions, Box<dyn Error>> {
+    let mut options: Value = serde_json::from_str(input)?;
+    remove_empty_options(&mut options);
+
+    Ok(PluginOptions::from_value(&options)?)
+}
+
+/// Removes empty values, so they fall back to the default options as in the plugin.
+fn remove_empty_options(options: &mut Value) {
+    if let Some(sections) = options.as_object_mut() {
+        for section in sections.values_mut() {
+            if let Some(section) = section.as_object_mut() {
+                section.retain(|_, value| !value.is_null() && value != "");
+            }
+        }
+    }
+}
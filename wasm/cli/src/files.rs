@@ -0,0 +1,95 @@
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// File extensions that are read when a directory is given.
+const MARKDOWN_EXTENSIONS: [&str; 2] = ["md", "markdown"];
+
+/// Collects Markdown files from files, directories and glob patterns.
+pub fn collect_files(inputs: &[String]) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    let mut files: Vec<PathBuf> = Vec::new();
+
+    for input in inputs {
+        let path = Path::new(input);
+
+        if path.is_file() {
+            files.push(path.to_path_buf());
+        } else if path.is_dir() {
+            read_directory(path, &mut files)?;
+        } else if is_glob_pattern(input) {
+            let mut matched = false;
+            for entry in glob::glob(input)? {
+                let entry = entry?;
+
+                // Hidden entries are only matched when the pattern names them.
+                if has_unnamed_hidden_component(&entry, input) {
+                    continue;
+                }
+                matched = true;
+
+                if entry.is_dir() {
+                    read_directory(&entry, &mut files)?;
+                } else if is_markdown_file(&entry) {
+                    files.push(entry);
+                }
+            }
+
+            if !matched {
+                return Err(format!("No files matched the pattern. ({})", input).into());
+            }
+        } else {
+            return Err(format!("No such file or directory. ({})", input).into());
+        }
+    }
+
+    files.sort();
+    files.dedup();
+
+    Ok(files)
+}
+
+/// Reads Markdown files in a directory recursively.
+/// Hidden entries such as `.obsidian` or `.git` are skipped.
+fn read_directory(directory: &Path, files: &mut Vec<PathBuf>) -> Result<(), Box<dyn Error>> {
+    for entry in fs::read_dir(directory)? {
+        let path = entry?.path();
+
+        if is_hidden(&path) {
+            continue;
+        }
+
+        if path.is_dir() {
+            read_directory(&path, files)?;
+        } else if is_markdown_file(&path) {
+            files.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+fn is_glob_pattern(input: &str) -> bool {
+    input.contains(['*', '?', '['].as_ref())
+}
+
+fn has_unnamed_hidden_component(path: &Path, pattern: &str) -> bool {
+    path.components().any(|component| {
+        let component = component.as_os_str().to_string_lossy();
+        component.starts_with('.')
+            && component != "."
+            && component != ".."
+            && !pattern.contains(component.as_ref())
+    })
+}
+
+fn is_hidden(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.starts_with('.'))
+}
+
+fn is_markdown_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| MARKDOWN_EXTENSIONS.contains(&extension))
+}
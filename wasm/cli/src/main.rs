@@ -0,0 +1,144 @@
+use clap::Parser;
+use std::error::Error;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use formatto_core::FormatConfig;
+
+mod config;
+mod files;
+
+#[cfg(test)]
+mod testing;
+
+/// Formats Markdown files the same way the Formatto plugin does.
+///
+/// Exit codes: 0 on success, 1 if `--check` found unformatted files,
+/// 2 if a file could not be read or parsed.
+#[derive(Debug, Parser)]
+#[command(name = "formatto", version)]
+struct Cli {
+    /// Files, directories or glob patterns to format. Use `-` to read from stdin.
+    #[arg(required = true)]
+    paths: Vec<String>,
+    /// Writes formatted output back to the files instead of stdout.
+    #[arg(short, long, conflicts_with = "check")]
+    write: bool,
+    /// Lists files that are not formatted on stdout without rewriting them.
+    /// Stdin is listed as `<stdin>`.
+    #[arg(long)]
+    check: bool,
+    /// A JSON option file. (ex: .obsidian/plugins/formatto/data.json)
+    #[arg(short, long)]
+    config: Option<PathBuf>,
+}
+
+/// The result of a run.
+#[derive(Debug, PartialEq)]
+enum Outcome {
+    /// Every file was formatted, or is already formatted.
+    Formatted,
+    /// `--check` found files that are not formatted.
+    Unformatted,
+    /// Some files could not be read or parsed.
+    Failed,
+}
+
+impl From<Outcome> for ExitCode {
+    fn from(outcome: Outcome) -> Self {
+        match outcome {
+            Outcome::Formatted => ExitCode::SUCCESS,
+            Outcome::Unformatted => ExitCode::from(1),
+            Outcome::Failed => ExitCode::from(2),
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match run(&cli) {
+        Ok(outcome) => outcome.into(),
+        Err(e) => {
+            eprintln!("error: {}", e);
+            Outcome::Failed.into()
+        }
+    }
+}
+
+fn run(cli: &Cli) -> Result<Outcome, Box<dyn Error>> {
+    let config = FormatConfig::new(config::read_options(cli.config.as_deref())?);
+
+    if cli.paths.iter().any(|path| path == "-") {
+        if cli.paths.len() > 1 || cli.write {
+            return Err("`-` cannot be used with other paths or `--write`.".into());
+        }
+
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)?;
+
+        if cli.check {
+            if formatto_core::check(&input, &config)? {
+                println!("<stdin>");
+                return Ok(Outcome::Unformatted);
+            }
+        } else {
+            io::stdout().write_all(formatto_core::format(&input, &config)?.as_bytes())?;
+        }
+
+        return Ok(Outcome::Formatted);
+    }
+
+    let files = files::collect_files(&cli.paths)?;
+    if files.len() > 1 && !cli.write && !cli.check {
+        return Err("Multiple files can only be formatted with `--write` or `--check`.".into());
+    }
+
+    let mut outcome = Outcome::Formatted;
+    for file in &files {
+        let result = if cli.check {
+            check_file(file, &config)
+        } else {
+            format_file(file, cli.write, &config).map(|_| true)
+        };
+
+        match result {
+            Ok(true) => {}
+            Ok(false) => {
+                println!("{}", file.display());
+                if outcome == Outcome::Formatted {
+                    outcome = Outcome::Unformatted;
+                }
+            }
+            Err(e) => {
+                eprintln!("{}: {}", file.display(), e);
+                outcome = Outcome::Failed;
+            }
+        }
+    }
+
+    Ok(outcome)
+}
+
+/// Checks a file and returns `false` if it is not formatted.
+fn check_file(path: &Path, config: &FormatConfig) -> Result<bool, Box<dyn Error>> {
+    let input = fs::read_to_string(path)?;
+
+    Ok(!formatto_core::check(&input, config)?)
+}
+
+/// Formats a file and writes the output to the file or stdout.
+fn format_file(path: &Path, write: bool, config: &FormatConfig) -> Result<(), Box<dyn Error>> {
+    let input = fs::read_to_string(path)?;
+    let output = formatto_core::format(&input, config)?;
+
+    if !write {
+        io::stdout().write_all(output.as_bytes())?;
+    } else if output != input {
+        fs::write(path, output)?;
+    }
+
+    Ok(())
+}
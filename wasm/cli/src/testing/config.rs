@@ -0,0 +1,52 @@
+use crate::config::parse_options;
+
+#[test]
+fn parse_options_1() {
+    let input = r#"
+    {
+        "headingGaps": {
+            "beforeTopLevelHeadings": "1",
+            "beforeFirstSubHeading": ""
+        },
+        "formatOptions": {
+            "insertNewline": false
+        }
+    }
+    "#;
+
+    let options = parse_options(input).unwrap();
+
+    assert_eq!(options.heading_gaps.before_top_level_headings, 1);
+    assert_eq!(options.heading_gaps.before_first_sub_heading, 1);
+    assert_eq!(options.heading_gaps.before_sub_headings, 2);
+    assert!(!options.format_options.insert_newline);
+}
+
+#[test]
+fn parse_options_2() {
+    let input = "{}";
+
+    let options = parse_options(input).unwrap();
+
+    assert_eq!(options.other_gaps.after_properties, 2);
+    assert!(options.format_options.insert_newline);
+}
+
+#[test]
+fn parse_options_3() {
+    let input = r#"{ "headingGaps": { "beforeSubHeadings": 4 } }"#;
+
+    let options = parse_options(input).unwrap();
+
+    assert_eq!(options.heading_gaps.before_sub_headings, 4);
+}
+
+#[test]
+fn invalid_options_1() {
+    let input = r#"{ "headingGaps": { "beforeSubHeadings": "-1" } }"#;
+
+    assert_eq!(
+        parse_options(input).unwrap_err().to_string(),
+        "Invalid option value. (headingGaps.beforeSubHeadings: \"-1\")"
+    );
+}
@@ -0,0 +1,75 @@
+use super::TempDir;
+use crate::files::collect_files;
+
+#[test]
+fn directory_1() {
+    // Markdown files are read recursively, and hidden entries are skipped.
+    let dir = TempDir::new("directory_1");
+    let a = dir.write("a.md", "");
+    let b = dir.write("notes/b.markdown", "");
+    dir.write("notes/c.txt", "");
+    dir.write("notes/.d.md", "");
+    dir.write(".obsidian/e.md", "");
+
+    let input = vec![dir.path().display().to_string()];
+
+    assert_eq!(collect_files(&input).unwrap(), vec![a, b]);
+}
+
+#[test]
+fn glob_1() {
+    let dir = TempDir::new("glob_1");
+    let a = dir.write("a.md", "");
+    let b = dir.write("notes/b.md", "");
+    dir.write("notes/c.markdown", "");
+    dir.write(".obsidian/d.md", "");
+
+    let input = vec![format!("{}/**/*.md", dir.path().display())];
+
+    assert_eq!(collect_files(&input).unwrap(), vec![a, b]);
+}
+
+#[test]
+fn glob_2() {
+    // Hidden entries are matched when the pattern names them.
+    let dir = TempDir::new("glob_2");
+    dir.write("a.md", "");
+    let b = dir.write(".obsidian/b.md", "");
+
+    let input = vec![format!("{}/.obsidian/*.md", dir.path().display())];
+
+    assert_eq!(collect_files(&input).unwrap(), vec![b]);
+}
+
+#[test]
+fn glob_3() {
+    // Files and patterns that match the same file are read once.
+    let dir = TempDir::new("glob_3");
+    let a = dir.write("a.md", "");
+
+    let input = vec![
+        a.display().to_string(),
+        format!("{}/*.md", dir.path().display()),
+    ];
+
+    assert_eq!(collect_files(&input).unwrap(), vec![a]);
+}
+
+#[test]
+fn invalid_input_1() {
+    let dir = TempDir::new("invalid_input_1");
+    dir.write("a.txt", "");
+
+    let input = vec![format!("{}/*.md", dir.path().display())];
+
+    assert!(collect_files(&input).is_err());
+}
+
+#[test]
+fn invalid_input_2() {
+    let dir = TempDir::new("invalid_input_2");
+
+    let input = vec![dir.path().join("a.md").display().to_string()];
+
+    assert!(collect_files(&input).is_err());
+}
@@ -0,0 +1,90 @@
+use clap::Parser;
+use std::fs;
+use std::process::ExitCode;
+
+use formatto_core::{FormatConfig, PluginOptions};
+
+use super::TempDir;
+use crate::{run, Cli, Outcome};
+
+const UNFORMATTED: &str = "## Heading 2\nLorem Ipsum";
+
+fn get_formatted_text() -> String {
+    formatto_core::format(UNFORMATTED, &FormatConfig::new(PluginOptions::default())).unwrap()
+}
+
+fn run_with_args(args: &[&str]) -> Result<Outcome, String> {
+    let cli = Cli::parse_from(std::iter::once("formatto").chain(args.iter().copied()));
+
+    run(&cli).map_err(|e| e.to_string())
+}
+
+#[test]
+fn check_1() {
+    let dir = TempDir::new("check_1");
+    let a = dir.write("a.md", &get_formatted_text());
+    let b = dir.write("notes/b.md", &get_formatted_text());
+
+    let outcome = run_with_args(&[
+        "--check",
+        &a.display().to_string(),
+        &b.display().to_string(),
+    ]);
+
+    assert_eq!(outcome, Ok(Outcome::Formatted));
+}
+
+#[test]
+fn check_2() {
+    // Unformatted files are not rewritten.
+    let dir = TempDir::new("check_2");
+    dir.write("a.md", &get_formatted_text());
+    let b = dir.write("b.md", UNFORMATTED);
+
+    let outcome = run_with_args(&["--check", &dir.path().display().to_string()]);
+
+    assert_eq!(outcome, Ok(Outcome::Unformatted));
+    assert_eq!(fs::read_to_string(b).unwrap(), UNFORMATTED);
+}
+
+#[test]
+fn check_3() {
+    // Files that cannot be parsed fail the run, after the other files are checked.
+    let dir = TempDir::new("check_3");
+    dir.write("a.md", UNFORMATTED);
+    dir.write("b.md", "```\ncode");
+
+    let outcome = run_with_args(&["--check", &dir.path().display().to_string()]);
+
+    assert_eq!(outcome, Ok(Outcome::Failed));
+}
+
+#[test]
+fn write_1() {
+    let dir = TempDir::new("write_1");
+    let a = dir.write("a.md", UNFORMATTED);
+
+    let outcome = run_with_args(&["--write", &format!("{}/*.md", dir.path().display())]);
+
+    assert_eq!(outcome, Ok(Outcome::Formatted));
+    assert_eq!(fs::read_to_string(a).unwrap(), get_formatted_text());
+}
+
+#[test]
+fn invalid_input_1() {
+    // Multiple files are not written to stdout.
+    let dir = TempDir::new("invalid_input_1");
+    dir.write("a.md", UNFORMATTED);
+    dir.write("b.md", UNFORMATTED);
+
+    let outcome = run_with_args(&[&dir.path().display().to_string()]);
+
+    assert!(outcome.is_err());
+}
+
+#[test]
+fn exit_codes_1() {
+    assert_eq!(ExitCode::from(Outcome::Formatted), ExitCode::SUCCESS);
+    assert_eq!(ExitCode::from(Outcome::Unformatted), ExitCode::from(1));
+    assert_eq!(ExitCode::from(Outcome::Failed), ExitCode::from(2));
+}
@@ -0,0 +1,64 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::tools::positions::Position;
+
+/// A range of a document. `end` is exclusive.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SourceSpan {
+    pub start: Position,
+    pub end: Position,
+}
+
+/// Error returned when a document cannot be formatted.
+///
+/// Messages are written in English.
+/// Hosts that need localized messages should match on the variants instead.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FormatError {
+    /// A code block is opened but never closed.
+    /// The span starts at the opening fence and ends at the end of the document.
+    UnclosedCodeBlock { span: SourceSpan },
+    /// A `%%` or `<!--` comment is opened but never closed.
+    /// The span starts at the opening marker and ends at the end of the document.
+    UnclosedComment { span: SourceSpan },
+    /// An option has a value that cannot be used.
+    /// `field` is the option path, such as `headingGaps.beforeSubHeadings`.
+    InvalidOption { field: String, value: String },
+}
+
+impl FormatError {
+    /// Returns the part of the document that caused the error.
+    pub fn span(&self) -> Option<&SourceSpan> {
+        match self {
+            FormatError::UnclosedCodeBlock { span } | FormatError::UnclosedComment { span } => {
+                Some(span)
+            }
+            FormatError::InvalidOption { .. } => None,
+        }
+    }
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatError::UnclosedCodeBlock { span } => write!(
+                f,
+                "A code block is not closed. [Line: {}, Column: {}]",
+                span.start.line + 1,
+                span.start.ch + 1
+            ),
+            FormatError::UnclosedComment { span } => write!(
+                f,
+                "A comment is not closed. [Line: {}, Column: {}]",
+                span.start.line + 1,
+                span.start.ch + 1
+            ),
+            FormatError::InvalidOption { field, value } => {
+                write!(f, "Invalid option value. ({}: \"{}\")", field, value)
+            }
+        }
+    }
+}
+
+impl Error for FormatError {}
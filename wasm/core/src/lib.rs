@@ -0,0 +1,121 @@
+//! Host-independent Markdown formatter used by Formatto.
+//!
+//! The Obsidian plugin reaches this crate through `formatto-wasm`,
+//! but it can be used from any Rust program.
+
+use std::ops::Range;
+
+pub use error::{FormatError, SourceSpan};
+pub use option_schema::PluginOptions;
+pub use tools::edits::TextEdit;
+pub use tools::positions::Position;
+
+mod error;
+pub mod option_schema;
+pub mod tools;
+
+#[cfg(test)]
+mod testing;
+
+/// Options used while formatting a document.
+#[derive(Debug)]
+pub struct FormatConfig {
+    pub options: PluginOptions,
+}
+
+impl FormatConfig {
+    pub fn new(options: PluginOptions) -> Self {
+        Self { options }
+    }
+}
+
+/// Formats a Markdown document.
+pub fn format(input: &str, config: &FormatConfig) -> Result<String, FormatError> {
+    match format_sections(input, config)? {
+        Some((_, output)) => Ok(output),
+        None => Ok(input.to_string()),
+    }
+}
+
+/// Checks whether a Markdown document is already formatted.
+/// Returns `true` if formatting would change the document.
+pub fn check(input: &str, config: &FormatConfig) -> Result<bool, FormatError> {
+    Ok(format(input, config)? != input)
+}
+
+/// Formats a Markdown document and returns the line edits to apply to it.
+/// Unchanged lines are not included in the edits.
+pub fn format_edits(input: &str, config: &FormatConfig) -> Result<Vec<TextEdit>, FormatError> {
+    let (sections, output) = match format_sections(input, config)? {
+        Some(formatted) => formatted,
+        None => return Ok(Vec::new()),
+    };
+
+    Ok(tools::edits::get_text_edits(input, &output, &sections))
+}
+
+/// Formats only the sections that overlap with a line range.
+/// Lines outside of the sections and the gaps before them are kept as they are.
+pub fn format_range(
+    input: &str,
+    config: &FormatConfig,
+    lines: Range<usize>,
+) -> Result<String, FormatError> {
+    let edits = format_range_edits(input, config, lines)?;
+
+    Ok(tools::edits::apply_text_edits(input, &edits))
+}
+
+/// Returns the line edits that format the sections overlapping with a line range.
+pub fn format_range_edits(
+    input: &str,
+    config: &FormatConfig,
+    lines: Range<usize>,
+) -> Result<Vec<TextEdit>, FormatError> {
+    let (sections, output) = match format_sections(input, config)? {
+        Some(formatted) => formatted,
+        None => return Ok(Vec::new()),
+    };
+
+    Ok(tools::edits::get_text_edits_in_range(
+        input, &output, &sections, lines,
+    ))
+}
+
+/// Formats a Markdown document and returns where each of the given positions
+/// ends up in the formatted output.
+pub fn map_positions(
+    input: &str,
+    config: &FormatConfig,
+    positions: &[Position],
+) -> Result<Vec<Position>, FormatError> {
+    let (sections, output) = match format_sections(input, config)? {
+        Some(formatted) => formatted,
+        None => return Ok(positions.to_vec()),
+    };
+
+    Ok(tools::positions::get_mapped_positions(
+        input, &output, &sections, positions,
+    ))
+}
+
+/// Parses and formats a document, and returns its sections with the output.
+/// Returns `None` if the document is empty or formatting is turned off for it.
+fn format_sections(
+    input: &str,
+    config: &FormatConfig,
+) -> Result<Option<(Vec<tools::tokens::MarkdownSection>, String)>, FormatError> {
+    if input.is_empty() {
+        return Ok(None);
+    }
+    let config = match tools::overrides::get_document_config(input, config)? {
+        Some(config) => config,
+        // Formatting is turned off for this document.
+        None => return Ok(None),
+    };
+
+    let sections = tools::parsing::get_sections(input, &config)?;
+    let output = tools::formatting::get_formatted_string(sections.clone(), &config)?;
+
+    Ok(Some((sections, output)))
+}
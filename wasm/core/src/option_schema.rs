@@ -0,0 +1,336 @@
+use serde::{de, Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use std::convert::TryFrom;
+use std::fmt;
+
+use crate::FormatError;
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct HeadingGaps {
+    /// Decides gaps before top level headings.
+    #[serde(deserialize_with = "deserialize_gap")]
+    pub before_top_level_headings: usize,
+    /// Decides child heading gaps right before parent headings.
+    #[serde(deserialize_with = "deserialize_gap")]
+    pub before_first_sub_heading: usize,
+    /// Decides gaps before headings that are not in the top level.
+    #[serde(deserialize_with = "deserialize_gap")]
+    pub before_sub_headings: usize,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct OtherGaps {
+    /// Decides the gap after the property section.
+    #[serde(deserialize_with = "deserialize_gap")]
+    pub after_properties: usize,
+    /// Decides gaps before content sections. (ex: Text before headings)
+    #[serde(deserialize_with = "deserialize_gap")]
+    pub before_contents: usize,
+    /// Decides gaps before 'contents that are after code blocks.'
+    #[serde(deserialize_with = "deserialize_gap")]
+    pub before_contents_after_code_blocks: usize,
+    /// Decides gaps before code blocks.
+    #[serde(deserialize_with = "deserialize_gap")]
+    pub before_code_blocks: usize,
+    /// Decides gaps before 'code blocks that are after headings.'
+    #[serde(deserialize_with = "deserialize_gap")]
+    pub before_code_blocks_after_headings: usize,
+    /// Decides gaps before 'contents that are after math blocks.'
+    #[serde(deserialize_with = "deserialize_gap")]
+    pub before_contents_after_math_blocks: usize,
+    /// Decides gaps before math blocks.
+    #[serde(deserialize_with = "deserialize_gap")]
+    pub before_math_blocks: usize,
+    /// Decides gaps before 'math blocks that are after headings.'
+    #[serde(deserialize_with = "deserialize_gap")]
+    pub before_math_blocks_after_headings: usize,
+    /// Decides gaps before 'contents that are after HTML blocks.'
+    #[serde(deserialize_with = "deserialize_gap")]
+    pub before_contents_after_html_blocks: usize,
+    /// Decides gaps before HTML blocks.
+    #[serde(deserialize_with = "deserialize_gap")]
+    pub before_html_blocks: usize,
+    /// Decides gaps before 'HTML blocks that are after headings.'
+    #[serde(deserialize_with = "deserialize_gap")]
+    pub before_html_blocks_after_headings: usize,
+    /// Decides gaps before callouts.
+    #[serde(deserialize_with = "deserialize_gap")]
+    pub before_callouts: usize,
+    /// Decides gaps after callouts.
+    #[serde(deserialize_with = "deserialize_gap")]
+    pub after_callouts: usize,
+    /// Decides gaps before lists.
+    #[serde(deserialize_with = "deserialize_gap")]
+    pub before_lists: usize,
+    /// Decides gaps before 'lists that are after headings.'
+    #[serde(deserialize_with = "deserialize_gap")]
+    pub before_lists_after_headings: usize,
+    /// Decides gaps before 'contents that are after lists.'
+    #[serde(deserialize_with = "deserialize_gap")]
+    pub before_contents_after_lists: usize,
+    /// Decides gaps before tables.
+    #[serde(deserialize_with = "deserialize_gap")]
+    pub before_tables: usize,
+    /// Decides gaps before 'tables that are after headings.'
+    #[serde(deserialize_with = "deserialize_gap")]
+    pub before_tables_after_headings: usize,
+    /// Decides gaps before 'contents that are after tables.'
+    #[serde(deserialize_with = "deserialize_gap")]
+    pub before_contents_after_tables: usize,
+    /// Decides gaps before and after horizontal rules.
+    #[serde(deserialize_with = "deserialize_gap")]
+    pub around_horizontal_rules: usize,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct FormatOptions {
+    /// Inserts a newline at the end of a document.
+    pub insert_newline: bool,
+    /// Decides which characters are used for thematic breaks.
+    pub thematic_break_style: ThematicBreakStyle,
+    /// Decides whether headings are written with hashes or underlines.
+    pub heading_style: HeadingStyle,
+    /// Writes one space after hashes, and removes closing hashes and trailing spaces of headings.
+    /// Hashes without a space after them are read as headings too. (ex: `##Heading`)
+    pub normalize_heading_syntax: bool,
+    /// Removes spaces before headings.
+    pub remove_heading_indentation: bool,
+    /// Renumbers headings, so that their levels do not skip.
+    pub fix_skipped_heading_levels: bool,
+    /// Demotes top level headings after the first one, with the headings under them.
+    /// Nothing is demoted if a level 6 heading would have to be.
+    pub demote_extra_top_level_headings: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ThematicBreakStyle {
+    /// Keeps thematic breaks as they are written.
+    #[default]
+    Preserve,
+    /// Writes thematic breaks as `***`.
+    Asterisks,
+    /// Writes thematic breaks as `___`.
+    Underscores,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum HeadingStyle {
+    /// Keeps headings as they are written.
+    #[default]
+    Preserve,
+    /// Writes headings with hashes. (ex: `## Heading`)
+    Atx,
+    /// Writes level 1 and 2 headings with underlines. (ex: `Heading` and `---`)
+    /// Deeper headings cannot be written this way, so they keep hashes.
+    Setext,
+}
+
+/// Options for YAML properties.
+/// None of them change properties by default.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PropertyOptions {
+    /// Decides the order of property keys.
+    pub key_order: PropertyKeyOrder,
+    /// Comma separated keys that come before other keys. (ex: `title, aliases, tags`)
+    pub priority_keys: String,
+    /// Decides how lists are written.
+    pub list_style: PropertyListStyle,
+    /// Decides which quotes are used for quoted values.
+    pub quote_style: PropertyQuoteStyle,
+    /// Removes blank lines between properties.
+    pub remove_blank_lines: bool,
+    /// Writes `tags`, `aliases` and `cssclasses` as lists,
+    /// without duplicates and `#` before tags.
+    pub normalize_list_properties: bool,
+    /// Writes tags in lowercase, when list properties are normalized.
+    pub lowercase_tags: bool,
+    /// Sorts values of list properties, when they are normalized.
+    pub sort_list_properties: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PropertyKeyOrder {
+    /// Keeps keys in the order they are written.
+    #[default]
+    Preserve,
+    /// Sorts keys alphabetically, ignoring cases.
+    Alphabetical,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PropertyListStyle {
+    /// Keeps lists as they are written.
+    #[default]
+    Preserve,
+    /// Writes lists in brackets. (ex: `[a, b]`)
+    Flow,
+    /// Writes each list item in its own line. (ex: `- a`)
+    Block,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PropertyQuoteStyle {
+    /// Keeps quotes as they are written.
+    #[default]
+    Preserve,
+    /// Writes quoted values with double quotes.
+    Double,
+    /// Writes quoted values with single quotes.
+    Single,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct OtherOptions {
+    /// Displays a different message when no change is needed.
+    pub notify_when_unchanged: bool,
+    /// Displays additional information when parsing fails.
+    pub show_more_detailed_error_messages: bool,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PluginOptions {
+    pub heading_gaps: HeadingGaps,
+    pub other_gaps: OtherGaps,
+    pub format_options: FormatOptions,
+    pub property_options: PropertyOptions,
+    pub other_options: OtherOptions,
+}
+
+impl PluginOptions {
+    /// Reads and validates options from JSON data, such as the plugin's `data.json`.
+    /// Missing options fall back to the default values.
+    pub fn from_value(value: &Value) -> Result<Self, FormatError> {
+        serde_path_to_error::deserialize(value).map_err(|e| {
+            let pointer: String = e
+                .path()
+                .iter()
+                .map(|segment| format!("/{}", segment))
+                .collect();
+            let invalid_value = match value.pointer(&pointer) {
+                Some(Value::String(invalid_value)) => invalid_value.clone(),
+                Some(invalid_value) => invalid_value.to_string(),
+                None => String::new(),
+            };
+
+            FormatError::InvalidOption {
+                field: e.path().to_string(),
+                value: invalid_value,
+            }
+        })
+    }
+}
+
+/// Reads a gap value.
+/// Older versions of the plugin store gaps as strings, so they are accepted too.
+fn deserialize_gap<'de, D: Deserializer<'de>>(deserializer: D) -> Result<usize, D::Error> {
+    struct GapVisitor;
+
+    impl<'de> de::Visitor<'de> for GapVisitor {
+        type Value = usize;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a whole number that is at least 0")
+        }
+
+        fn visit_u64<E: de::Error>(self, value: u64) -> Result<usize, E> {
+            usize::try_from(value)
+                .map_err(|_| E::invalid_value(de::Unexpected::Unsigned(value), &self))
+        }
+
+        fn visit_i64<E: de::Error>(self, value: i64) -> Result<usize, E> {
+            usize::try_from(value)
+                .map_err(|_| E::invalid_value(de::Unexpected::Signed(value), &self))
+        }
+
+        fn visit_f64<E: de::Error>(self, value: f64) -> Result<usize, E> {
+            if value >= 0.0 && value.fract() == 0.0 && value <= u32::MAX as f64 {
+                Ok(value as usize)
+            } else {
+                Err(E::invalid_value(de::Unexpected::Float(value), &self))
+            }
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<usize, E> {
+            value
+                .trim()
+                .parse::<usize>()
+                .map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self))
+        }
+    }
+
+    deserializer.deserialize_any(GapVisitor)
+}
+
+// Default values are the same as the fallback options of the plugin.
+// (src/obsidian/options/optionTypes.ts)
+
+impl Default for HeadingGaps {
+    fn default() -> Self {
+        Self {
+            before_top_level_headings: 3,
+            before_first_sub_heading: 1,
+            before_sub_headings: 2,
+        }
+    }
+}
+
+impl Default for OtherGaps {
+    fn default() -> Self {
+        Self {
+            after_properties: 2,
+            before_contents: 0,
+            before_contents_after_code_blocks: 1,
+            before_code_blocks: 1,
+            before_code_blocks_after_headings: 0,
+            before_contents_after_math_blocks: 1,
+            before_math_blocks: 1,
+            before_math_blocks_after_headings: 0,
+            before_contents_after_html_blocks: 1,
+            before_html_blocks: 1,
+            before_html_blocks_after_headings: 0,
+            before_callouts: 1,
+            after_callouts: 1,
+            before_lists: 0,
+            before_lists_after_headings: 0,
+            before_contents_after_lists: 1,
+            before_tables: 0,
+            before_tables_after_headings: 0,
+            before_contents_after_tables: 1,
+            around_horizontal_rules: 1,
+        }
+    }
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self {
+            insert_newline: true,
+            thematic_break_style: ThematicBreakStyle::Preserve,
+            heading_style: HeadingStyle::Preserve,
+            normalize_heading_syntax: false,
+            remove_heading_indentation: false,
+            fix_skipped_heading_levels: false,
+            demote_extra_top_level_headings: false,
+        }
+    }
+}
+
+impl Default for OtherOptions {
+    fn default() -> Self {
+        Self {
+            notify_when_unchanged: true,
+            show_more_detailed_error_messages: false,
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use crate::FormatConfig;
+
+use crate::option_schema::{
+    FormatOptions, HeadingGaps, HeadingStyle, OtherGaps, OtherOptions, PluginOptions,
+    PropertyOptions, ThematicBreakStyle,
+};
+
+mod edits;
+mod errors;
+mod format;
+mod formatting;
+mod option_schema;
+mod overrides;
+mod parsing;
+mod positions;
+mod range;
+
+/// Returns an example value for testing.
+fn get_example_config() -> FormatConfig {
+    FormatConfig::new(get_example_options())
+}
+
+/// Returns an example option value.
+fn get_example_options() -> PluginOptions {
+    PluginOptions {
+        heading_gaps: HeadingGaps {
+            before_top_level_headings: 3,
+            before_first_sub_heading: 1,
+            before_sub_headings: 2,
+        },
+        other_gaps: OtherGaps {
+            after_properties: 2,
+            before_contents: 0,
+            before_contents_after_code_blocks: 1,
+            before_code_blocks: 1,
+            before_code_blocks_after_headings: 0,
+            before_contents_after_math_blocks: 1,
+            before_math_blocks: 1,
+            before_math_blocks_after_headings: 0,
+            before_contents_after_html_blocks: 1,
+            before_html_blocks: 1,
+            before_html_blocks_after_headings: 0,
+            before_callouts: 1,
+            after_callouts: 1,
+            before_lists: 0,
+            before_lists_after_headings: 0,
+            before_contents_after_lists: 1,
+            before_tables: 1,
+            before_tables_after_headings: 0,
+            before_contents_after_tables: 1,
+            around_horizontal_rules: 1,
+        },
+        format_options: FormatOptions {
+            insert_newline: false,
+            thematic_break_style: ThematicBreakStyle::Preserve,
+            heading_style: HeadingStyle::Preserve,
+            normalize_heading_syntax: false,
+            remove_heading_indentation: false,
+            fix_skipped_heading_levels: false,
+            demote_extra_top_level_headings: false,
+        },
+        property_options: PropertyOptions::default(),
+        other_options: OtherOptions {
+            notify_when_unchanged: false,
+            show_more_detailed_error_messages: false,
+        },
+    }
+}
@@ -0,0 +1,240 @@
+use crate::{
+    format, format_edits,
+    option_schema::{HeadingStyle, PropertyListStyle},
+    testing::{get_example_config, get_example_options},
+    tools::edits::{apply_text_edits, TextEdit},
+    FormatConfig,
+};
+
+#[test]
+fn case_1() {
+    let input = r#"## Heading 2
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+### Heading 3
+
+
+
+Lorem Ipsum is simply dummy text of the printing and typesetting industry."#;
+
+    let edits = format_edits(input, &get_example_config()).unwrap();
+    let expected_output = vec![
+        TextEdit {
+            start_line: 2,
+            end_line: 2,
+            replacement: "\n".to_string(),
+        },
+        TextEdit {
+            start_line: 3,
+            end_line: 6,
+            replacement: String::new(),
+        },
+    ];
+
+    assert_eq!(edits, expected_output);
+}
+
+#[test]
+fn case_2() {
+    let input = r#"## Heading 2
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+
+### Heading 3"#;
+
+    let edits = format_edits(input, &get_example_config()).unwrap();
+
+    assert_eq!(edits, Vec::new());
+}
+
+#[test]
+fn case_3() {
+    let input = r#"
+
+---
+aliases:
+    - Test
+---
+## Heading 2
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.   
+```rust
+fn main() {}
+```
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+"#;
+
+    let mut options = get_example_options();
+    options.format_options.insert_newline = true;
+    let config = FormatConfig::new(options);
+
+    let edits = format_edits(input, &config).unwrap();
+
+    assert_eq!(
+        apply_text_edits(input, &edits),
+        format(input, &config).unwrap()
+    );
+}
+
+#[test]
+fn case_4() {
+    let input = r#"Heading 1
+=========
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+
+
+
+Heading 2
+---------
+Lorem Ipsum is simply dummy text of the printing and typesetting industry."#;
+
+    let edits = format_edits(input, &get_example_config()).unwrap();
+
+    assert_eq!(
+        apply_text_edits(input, &edits),
+        format(input, &get_example_config()).unwrap()
+    );
+    assert_eq!(edits.len(), 1);
+}
+
+#[test]
+fn case_5() {
+    let input = r#"## Heading 2
+> [!note]
+> ## Heading
+> Lorem Ipsum
+
+Lorem Ipsum
+
+### Heading 3"#;
+
+    let edits = format_edits(input, &get_example_config()).unwrap();
+    let expected_output = vec![TextEdit {
+        start_line: 1,
+        end_line: 4,
+        replacement: "\n> [!note]\n>\n>\n>\n> ## Heading\n> Lorem Ipsum\n".to_string(),
+    }];
+
+    assert_eq!(edits, expected_output);
+    assert_eq!(
+        apply_text_edits(input, &edits),
+        format(input, &get_example_config()).unwrap()
+    );
+}
+
+#[test]
+fn case_6() {
+    let input = r#"---
+tags:
+  - a
+  - b
+---
+## Heading 2
+Lorem Ipsum"#;
+
+    let mut options = get_example_options();
+    options.property_options.list_style = PropertyListStyle::Flow;
+    let config = FormatConfig::new(options);
+
+    let edits = format_edits(input, &config).unwrap();
+    let expected_output = vec![TextEdit {
+        start_line: 0,
+        end_line: 5,
+        replacement: "---\ntags: [a, b]\n---\n\n\n".to_string(),
+    }];
+
+    assert_eq!(edits, expected_output);
+    assert_eq!(
+        apply_text_edits(input, &edits),
+        format(input, &config).unwrap()
+    );
+}
+
+#[test]
+fn case_7() {
+    let input = r#"Heading 1
+=========
+Lorem Ipsum
+
+## Heading 2
+Lorem Ipsum"#;
+
+    let mut options = get_example_options();
+    options.format_options.heading_style = HeadingStyle::Atx;
+    let config = FormatConfig::new(options);
+
+    let edits = format_edits(input, &config).unwrap();
+    let expected_output = vec![TextEdit {
+        start_line: 0,
+        end_line: 2,
+        replacement: "# Heading 1\n".to_string(),
+    }];
+
+    assert_eq!(edits, expected_output);
+    assert_eq!(
+        apply_text_edits(input, &edits),
+        format(input, &config).unwrap()
+    );
+}
+
+#[test]
+fn case_8() {
+    // A thematic break after a hash heading is not its underline.
+    let input = r#"# Heading 1
+---
+Lorem Ipsum
+## Heading 2
+Lorem Ipsum"#;
+
+    let edits = format_edits(input, &get_example_config()).unwrap();
+    let expected_output = vec![
+        TextEdit {
+            start_line: 1,
+            end_line: 1,
+            replacement: "\n".to_string(),
+        },
+        TextEdit {
+            start_line: 2,
+            end_line: 2,
+            replacement: "\n".to_string(),
+        },
+        TextEdit {
+            start_line: 3,
+            end_line: 3,
+            replacement: "\n".to_string(),
+        },
+    ];
+
+    assert_eq!(edits, expected_output);
+    assert_eq!(
+        apply_text_edits(input, &edits),
+        format(input, &get_example_config()).unwrap()
+    );
+}
+
+#[test]
+fn case_9() {
+    // Hashes without a space after them are one line, even before an underline.
+    let input = "##Title\n---\nLorem Ipsum";
+
+    let mut options = get_example_options();
+    options.format_options.normalize_heading_syntax = true;
+    let config = FormatConfig::new(options);
+
+    let edits = format_edits(input, &config).unwrap();
+    let expected_output = vec![
+        TextEdit {
+            start_line: 0,
+            end_line: 1,
+            replacement: "## Title\n\n".to_string(),
+        },
+        TextEdit {
+            start_line: 2,
+            end_line: 2,
+            replacement: "\n".to_string(),
+        },
+    ];
+
+    assert_eq!(edits, expected_output);
+    assert_eq!(
+        apply_text_edits(input, &edits),
+        format(input, &config).unwrap()
+    );
+}
@@ -0,0 +1,79 @@
+use crate::{format, testing::get_example_config, FormatError, Position, SourceSpan};
+
+#[test]
+fn unclosed_code_block_1() {
+    let input = r#"
+
+## Heading 2
+```rust
+fn main() {}"#;
+
+    let expected_output = FormatError::UnclosedCodeBlock {
+        span: SourceSpan {
+            start: Position { line: 3, ch: 0 },
+            end: Position { line: 4, ch: 12 },
+        },
+    };
+
+    assert_eq!(
+        format(input, &get_example_config()).unwrap_err(),
+        expected_output
+    );
+}
+
+#[test]
+fn unclosed_code_block_2() {
+    let input = r#"## Heading 2
+> [!note]
+> ```rust
+> fn main() {}"#;
+
+    let expected_output = FormatError::UnclosedCodeBlock {
+        span: SourceSpan {
+            start: Position { line: 2, ch: 2 },
+            end: Position { line: 3, ch: 14 },
+        },
+    };
+
+    assert_eq!(
+        format(input, &get_example_config()).unwrap_err(),
+        expected_output
+    );
+}
+
+#[test]
+fn unclosed_comment_1() {
+    let input = r#"## Heading 2
+  %%
+## Commented Heading"#;
+
+    let expected_output = FormatError::UnclosedComment {
+        span: SourceSpan {
+            start: Position { line: 1, ch: 2 },
+            end: Position { line: 2, ch: 20 },
+        },
+    };
+
+    assert_eq!(
+        format(input, &get_example_config()).unwrap_err(),
+        expected_output
+    );
+}
+
+#[test]
+fn unclosed_comment_2() {
+    let input = r#"<!-- Comment
+## Heading 2"#;
+
+    let expected_output = FormatError::UnclosedComment {
+        span: SourceSpan {
+            start: Position { line: 0, ch: 0 },
+            end: Position { line: 1, ch: 12 },
+        },
+    };
+
+    assert_eq!(
+        format(input, &get_example_config()).unwrap_err(),
+        expected_output
+    );
+}
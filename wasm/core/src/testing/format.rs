@@ -0,0 +1,107 @@
+use crate::{
+    check, format, option_schema::PluginOptions, testing::get_example_options, FormatConfig,
+};
+
+#[test]
+fn case_1() {
+    let input = r#"# Heading 1
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+## Heading 2
+Lorem Ipsum is simply dummy text of the printing and typesetting industry."#;
+
+    let config = FormatConfig::new(get_example_options());
+    let expected_output = r#"# Heading 1
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+
+## Heading 2
+Lorem Ipsum is simply dummy text of the printing and typesetting industry."#;
+
+    assert_eq!(format(input, &config).unwrap(), expected_output);
+}
+
+#[test]
+fn empty_input() {
+    let config = FormatConfig::new(get_example_options());
+
+    assert_eq!(format("", &config).unwrap(), "");
+}
+
+#[test]
+fn invalid_input_1() {
+    let input = r#"```
+code"#;
+
+    let config = FormatConfig::new(get_example_options());
+    let error = format(input, &config).unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "A code block is not closed. [Line: 1, Column: 1]"
+    );
+}
+
+#[test]
+fn check_1() {
+    let config = FormatConfig::new(get_example_options());
+
+    assert!(check("# Heading 1\n\n\nLorem Ipsum", &config).unwrap());
+    assert!(!check("# Heading 1\nLorem Ipsum", &config).unwrap());
+}
+
+#[test]
+fn check_invalid_input_1() {
+    let config = FormatConfig::new(get_example_options());
+
+    assert!(check("```\ncode", &config).is_err());
+}
+
+#[test]
+fn default_options_1() {
+    // Default options do not add gaps before lists and tables after headings and text,
+    // where lists and tables were read as contents before.
+    let input = r#"# Heading 1
+1. one
+## Heading 2
+| a |
+| - |
+
+intro
+- item
+```
+code
+```
+- item"#;
+
+    let config = FormatConfig::new(PluginOptions::default());
+    let expected_output = r#"# Heading 1
+1. one
+
+## Heading 2
+| a |
+| - |
+
+intro
+- item
+
+```
+code
+```
+
+- item
+"#;
+
+    assert_eq!(format(input, &config).unwrap(), expected_output);
+}
+
+#[test]
+fn crlf_1() {
+    // Properties of documents with CRLF line breaks are read as properties.
+    let input = "---\r\ntitle: Lorem Ipsum\r\n---\r\n## Heading\r\nLorem Ipsum\r\n";
+
+    let config = FormatConfig::new(get_example_options());
+
+    assert_eq!(
+        format(input, &config).unwrap(),
+        "---\r\ntitle: Lorem Ipsum\r\n---\r\n\n\n## Heading\r\nLorem Ipsum"
+    );
+}
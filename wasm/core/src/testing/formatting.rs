@@ -0,0 +1,16 @@
+mod blockquotes;
+mod blocks;
+mod code_blocks;
+mod comments;
+mod html_blocks;
+mod ignores;
+mod math_blocks;
+mod properties;
+
+mod headings {
+    mod alternate_headings;
+    mod hash_headings;
+    mod heading_levels;
+    mod heading_styles;
+    mod heading_syntax;
+}
@@ -0,0 +1,151 @@
+use crate::{
+    testing::get_example_config,
+    tools::{formatting::get_formatted_string, parsing::get_sections},
+};
+
+#[test]
+fn case_1() {
+    let input = r#"## Heading 2
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+> [!note] Title
+> ## Heading
+> Lorem Ipsum
+> ### Heading
+>
+>
+> Lorem Ipsum
+
+Lorem Ipsum"#;
+
+    let sections = get_sections(input, &get_example_config()).unwrap();
+    let output = get_formatted_string(sections, &get_example_config()).unwrap();
+    let expected_output = r#"## Heading 2
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+
+> [!note] Title
+>
+>
+>
+> ## Heading
+> Lorem Ipsum
+>
+> ### Heading
+> Lorem Ipsum
+
+Lorem Ipsum"#;
+
+    assert_eq!(output, expected_output);
+}
+
+#[test]
+fn case_2() {
+    let input = r#"## Heading 2
+> Lorem Ipsum
+>
+> > Nested
+> > ## Heading
+> > Lorem Ipsum
+
+Lorem Ipsum
+> Lorem Ipsum
+```rust
+fn main() {}
+```"#;
+
+    let sections = get_sections(input, &get_example_config()).unwrap();
+    let output = get_formatted_string(sections, &get_example_config()).unwrap();
+    let expected_output = r#"## Heading 2
+> Lorem Ipsum
+> > Nested
+> >
+> >
+> >
+> > ## Heading
+> > Lorem Ipsum
+
+Lorem Ipsum
+> Lorem Ipsum
+
+```rust
+fn main() {}
+```"#;
+
+    assert_eq!(output, expected_output);
+}
+
+#[test]
+fn case_3() {
+    // The prefix comes from the first line with text.
+    let input = r#"# T
+>
+> [!note] t
+> body"#;
+
+    let sections = get_sections(input, &get_example_config()).unwrap();
+    let output = get_formatted_string(sections, &get_example_config()).unwrap();
+    let expected_output = r#"# T
+
+> [!note] t
+> body"#;
+
+    assert_eq!(output, expected_output);
+
+    let sections = get_sections(&output, &get_example_config()).unwrap();
+    let output = get_formatted_string(sections, &get_example_config()).unwrap();
+
+    assert_eq!(output, expected_output);
+}
+
+#[test]
+fn case_4() {
+    // Lines without `>` that continue a paragraph stay in the blockquote.
+    let input = r#"# A
+> quote
+> more
+text
+
+> quote
+| a | b |
+==="#;
+
+    let sections = get_sections(input, &get_example_config()).unwrap();
+    let output = get_formatted_string(sections, &get_example_config()).unwrap();
+    let expected_output = r#"# A
+> quote
+> more
+text
+
+> quote
+| a | b |
+==="#;
+
+    assert_eq!(output, expected_output);
+
+    let sections = get_sections(&output, &get_example_config()).unwrap();
+    let output = get_formatted_string(sections, &get_example_config()).unwrap();
+
+    assert_eq!(output, expected_output);
+}
+
+#[test]
+fn case_5() {
+    // Blockquotes are not merged into the blockquotes before them.
+    let input = r#"# A
+> first
+
+> second"#;
+
+    let sections = get_sections(input, &get_example_config()).unwrap();
+    let output = get_formatted_string(sections, &get_example_config()).unwrap();
+    let expected_output = r#"# A
+> first
+
+> second"#;
+
+    assert_eq!(output, expected_output);
+
+    let sections = get_sections(&output, &get_example_config()).unwrap();
+    let output = get_formatted_string(sections, &get_example_config()).unwrap();
+
+    assert_eq!(output, expected_output);
+}
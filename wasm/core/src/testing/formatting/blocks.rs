@@ -0,0 +1,135 @@
+use crate::{
+    option_schema::ThematicBreakStyle,
+    testing::get_example_config,
+    tools::{formatting::get_formatted_string, parsing::get_sections},
+};
+
+#[test]
+fn case_1() {
+    let input = r#"## Heading 2
+Lorem Ipsum:
+
+- Item
+- Item
+
+Lorem Ipsum
+
+
+| A | B |
+| - | - |
+| 1 | 2 |
+
+Lorem Ipsum
+***
+Lorem Ipsum"#;
+
+    let sections = get_sections(input, &get_example_config()).unwrap();
+    let output = get_formatted_string(sections, &get_example_config()).unwrap();
+    let expected_output = r#"## Heading 2
+Lorem Ipsum:
+- Item
+- Item
+
+Lorem Ipsum
+
+| A | B |
+| - | - |
+| 1 | 2 |
+
+Lorem Ipsum
+
+***
+
+Lorem Ipsum"#;
+
+    assert_eq!(output, expected_output);
+}
+
+#[test]
+fn case_2() {
+    // Gaps that would change the meaning of the document are kept.
+    let input = r#"Lorem Ipsum
+
+2. Item
+
+Lorem Ipsum
+
+---"#;
+
+    let mut config = get_example_config();
+    config.options.other_gaps.around_horizontal_rules = 0;
+    config.options.other_gaps.before_contents_after_lists = 0;
+
+    let sections = get_sections(input, &config).unwrap();
+    let output = get_formatted_string(sections, &config).unwrap();
+    let expected_output = r#"Lorem Ipsum
+
+2. Item
+
+Lorem Ipsum
+
+---"#;
+
+    assert_eq!(output, expected_output);
+}
+
+#[test]
+fn case_3() {
+    let input = r#"Lorem Ipsum
+
+---
+Lorem Ipsum
+- - -"#;
+
+    let mut config = get_example_config();
+    config.options.format_options.thematic_break_style = ThematicBreakStyle::Asterisks;
+
+    let sections = get_sections(input, &config).unwrap();
+    let output = get_formatted_string(sections, &config).unwrap();
+    let expected_output = r#"Lorem Ipsum
+
+***
+
+Lorem Ipsum
+
+***"#;
+
+    assert_eq!(output, expected_output);
+
+    config.options.format_options.thematic_break_style = ThematicBreakStyle::Underscores;
+
+    let sections = get_sections(input, &config).unwrap();
+    let output = get_formatted_string(sections, &config).unwrap();
+
+    assert_eq!(output, expected_output.replace("***", "___"));
+}
+
+#[test]
+fn case_4() {
+    // Lists and tables right after headings have their own gaps.
+    let input = r#"## Heading 2
+- Item
+## Heading 2
+| A |
+| - |"#;
+
+    let mut config = get_example_config();
+    config.options.other_gaps.before_lists_after_headings = 1;
+    config.options.other_gaps.before_tables_after_headings = 2;
+
+    let sections = get_sections(input, &config).unwrap();
+    let output = get_formatted_string(sections, &config).unwrap();
+    let expected_output = r#"## Heading 2
+
+- Item
+
+
+
+## Heading 2
+
+
+| A |
+| - |"#;
+
+    assert_eq!(output, expected_output);
+}
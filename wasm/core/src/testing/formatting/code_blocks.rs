@@ -1,12 +1,10 @@
 use crate::{
-    testing::{get_example_preferences, setup},
+    testing::get_example_config,
     tools::{formatting::get_formatted_string, parsing::get_sections},
 };
 
 #[test]
 fn case_1() {
-    setup();
-
     let input = r#"## Heading 2
 Lorem Ipsum is simply dummy text of the printing and typesetting industry.
 
@@ -17,8 +15,8 @@ fn main(
 ) {}
 ```"#;
 
-    let sections = get_sections(input, &get_example_preferences()).unwrap();
-    let output = get_formatted_string(sections, &get_example_preferences()).unwrap();
+    let sections = get_sections(input, &get_example_config()).unwrap();
+    let output = get_formatted_string(sections, &get_example_config()).unwrap();
     let expected_output = r#"## Heading 2
 Lorem Ipsum is simply dummy text of the printing and typesetting industry.
 
@@ -34,8 +32,6 @@ fn main(
 
 #[test]
 fn case_2() {
-    setup();
-
     let input = r#"## Heading 2
 
 ```rust
@@ -48,8 +44,8 @@ fn main(
 Lorem Ipsum is simply dummy text of the printing and typesetting industry.
 "#;
 
-    let sections = get_sections(input, &get_example_preferences()).unwrap();
-    let output = get_formatted_string(sections, &get_example_preferences()).unwrap();
+    let sections = get_sections(input, &get_example_config()).unwrap();
+    let output = get_formatted_string(sections, &get_example_config()).unwrap();
     let expected_output = r#"## Heading 2
 ```rust
 fn main(
@@ -61,3 +57,19 @@ Lorem Ipsum is simply dummy text of the printing and typesetting industry."#;
 
     assert_eq!(output, expected_output);
 }
+
+#[test]
+fn case_3() {
+    // Code blocks in list items do not get gaps.
+    let input = r#"## Heading 2
+1. Install
+   ```bash
+   npm install
+   ```
+2. Run"#;
+
+    let sections = get_sections(input, &get_example_config()).unwrap();
+    let output = get_formatted_string(sections, &get_example_config()).unwrap();
+
+    assert_eq!(output, input);
+}
@@ -0,0 +1,65 @@
+use crate::{
+    testing::get_example_config,
+    tools::{formatting::get_formatted_string, parsing::get_sections},
+};
+
+#[test]
+fn case_1() {
+    let input = r#"## Heading 2
+
+
+%%
+# Commented Heading
+Lorem Ipsum
+%%
+```rust
+fn main() {}
+```
+<!--
+Lorem Ipsum
+
+-->"#;
+
+    let sections = get_sections(input, &get_example_config()).unwrap();
+    let output = get_formatted_string(sections, &get_example_config()).unwrap();
+    let expected_output = r#"## Heading 2
+%%
+# Commented Heading
+Lorem Ipsum
+%%
+
+```rust
+fn main() {}
+```
+
+<!--
+Lorem Ipsum
+
+-->"#;
+
+    assert_eq!(output, expected_output);
+}
+
+#[test]
+fn case_2() {
+    // Headings inside comments are kept as they are.
+    let input = r#"## Heading 2
+Lorem Ipsum %%
+#   Commented Heading
+%%
+%% a %% b %%
+#   Commented Heading
+%%"#;
+
+    let sections = get_sections(input, &get_example_config()).unwrap();
+    let output = get_formatted_string(sections, &get_example_config()).unwrap();
+    let expected_output = r#"## Heading 2
+Lorem Ipsum %%
+#   Commented Heading
+%%
+%% a %% b %%
+#   Commented Heading
+%%"#;
+
+    assert_eq!(output, expected_output);
+}
@@ -1,12 +1,10 @@
 use crate::{
-    testing::{get_example_preferences, setup},
+    testing::get_example_config,
     tools::{formatting::get_formatted_string, parsing::get_sections},
 };
 
 #[test]
 fn case_1() {
-    setup();
-
     let input = r#"## Heading 2
 
 ### heading 3
@@ -17,8 +15,8 @@ console.log("Hello World");
 Heading 1
 ==="#;
 
-    let sections = get_sections(input, &get_example_preferences()).unwrap();
-    let output = get_formatted_string(sections, &get_example_preferences()).unwrap();
+    let sections = get_sections(input, &get_example_config()).unwrap();
+    let output = get_formatted_string(sections, &get_example_config()).unwrap();
     let expected_output = r#"## Heading 2
 
 ### heading 3
@@ -36,8 +34,6 @@ Heading 1
 
 #[test]
 fn case_2() {
-    setup();
-
     let input = r#"Heading 1
 ==
 
@@ -46,8 +42,8 @@ Heading 1
 
 ### Heading 3"#;
 
-    let sections = get_sections(input, &get_example_preferences()).unwrap();
-    let output = get_formatted_string(sections, &get_example_preferences()).unwrap();
+    let sections = get_sections(input, &get_example_config()).unwrap();
+    let output = get_formatted_string(sections, &get_example_config()).unwrap();
     let expected_output = r#"Heading 1
 ==
 
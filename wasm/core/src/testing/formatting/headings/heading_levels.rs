@@ -0,0 +1,124 @@
+use crate::{
+    format,
+    testing::get_example_options,
+    tools::{formatting::get_formatted_string, parsing::get_sections},
+    FormatConfig,
+};
+
+/// Skipped levels are filled, keeping which headings contain which.
+#[test]
+fn case_1() {
+    let input = r#"# A
+### B
+##### C
+## D
+#### E"#;
+
+    let mut options = get_example_options();
+    options.format_options.fix_skipped_heading_levels = true;
+    let config = FormatConfig::new(options);
+
+    let sections = get_sections(input, &config).unwrap();
+    let output = get_formatted_string(sections, &config).unwrap();
+    let expected_output = r#"# A
+
+## B
+
+### C
+
+
+## D
+
+### E"#;
+
+    assert_eq!(output, expected_output);
+}
+
+/// Top level headings after the first one are demoted, with the headings under them.
+#[test]
+fn case_2() {
+    let input = r#"# A
+Lorem Ipsum
+# B
+## C
+
+D
+===
+Lorem Ipsum"#;
+
+    let mut options = get_example_options();
+    options.format_options.demote_extra_top_level_headings = true;
+    let config = FormatConfig::new(options);
+
+    let sections = get_sections(input, &config).unwrap();
+    let output = get_formatted_string(sections, &config).unwrap();
+    let expected_output = r#"# A
+Lorem Ipsum
+
+## B
+
+### C
+
+
+D
+---
+Lorem Ipsum"#;
+
+    assert_eq!(output, expected_output);
+}
+
+/// Alternate headings deeper than level 2 are written with hashes.
+#[test]
+fn case_3() {
+    let input = r#"# A
+### B
+# C
+D
+---
+Lorem Ipsum"#;
+
+    let mut options = get_example_options();
+    options.format_options.fix_skipped_heading_levels = true;
+    options.format_options.demote_extra_top_level_headings = true;
+    let config = FormatConfig::new(options);
+
+    let output = format(input, &config).unwrap();
+    let expected_output = r#"# A
+
+## B
+
+
+## C
+
+### D
+Lorem Ipsum"#;
+
+    assert_eq!(output, expected_output);
+    assert_eq!(format(&output, &config).unwrap(), output);
+}
+
+/// Nothing is demoted if a level 6 heading would have to be, so that levels are not merged.
+#[test]
+fn case_4() {
+    let input = r#"# A
+# B
+##### C
+###### D"#;
+
+    let mut options = get_example_options();
+    options.format_options.demote_extra_top_level_headings = true;
+    let config = FormatConfig::new(options);
+
+    let output = format(input, &config).unwrap();
+    let expected_output = r#"# A
+
+
+
+# B
+
+##### C
+
+###### D"#;
+
+    assert_eq!(output, expected_output);
+}
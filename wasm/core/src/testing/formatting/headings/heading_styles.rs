@@ -0,0 +1,88 @@
+use crate::{
+    option_schema::HeadingStyle,
+    testing::get_example_options,
+    tools::{formatting::get_formatted_string, parsing::get_sections},
+    FormatConfig,
+};
+
+/// Alternate headings are rewritten with hashes.
+#[test]
+fn case_1() {
+    let input = r#"Heading 1
+=========
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+
+Heading 2
+---
+### Heading 3"#;
+
+    let mut options = get_example_options();
+    options.format_options.heading_style = HeadingStyle::Atx;
+    let config = FormatConfig::new(options);
+
+    let sections = get_sections(input, &config).unwrap();
+    let output = get_formatted_string(sections, &config).unwrap();
+    let expected_output = r#"# Heading 1
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+
+## Heading 2
+
+### Heading 3"#;
+
+    assert_eq!(output, expected_output);
+}
+
+/// Hash headings are rewritten with underlines, except level 3 and deeper headings.
+#[test]
+fn case_2() {
+    let input = r#"# Heading 1
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+## Heading 2 ##
+### Heading 3"#;
+
+    let mut options = get_example_options();
+    options.format_options.heading_style = HeadingStyle::Setext;
+    let config = FormatConfig::new(options);
+
+    let sections = get_sections(input, &config).unwrap();
+    let output = get_formatted_string(sections, &config).unwrap();
+    let expected_output = r#"Heading 1
+=========
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+
+Heading 2
+---------
+
+### Heading 3"#;
+
+    assert_eq!(output, expected_output);
+}
+
+/// Alternate headings keep a gap before them, and titles that cannot be underlined keep hashes.
+#[test]
+fn case_3() {
+    let input = r#"# A
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+## - Item
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+## B"#;
+
+    let mut options = get_example_options();
+    options.heading_gaps.before_first_sub_heading = 0;
+    options.heading_gaps.before_sub_headings = 0;
+    options.format_options.heading_style = HeadingStyle::Setext;
+    let config = FormatConfig::new(options);
+
+    let sections = get_sections(input, &config).unwrap();
+    let output = get_formatted_string(sections, &config).unwrap();
+    let expected_output = r#"A
+===
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+## - Item
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+
+B
+---"#;
+
+    assert_eq!(output, expected_output);
+}
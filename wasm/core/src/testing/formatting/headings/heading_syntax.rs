@@ -0,0 +1,113 @@
+use crate::{
+    testing::get_example_options,
+    tools::{formatting::get_formatted_string, parsing::get_sections},
+    FormatConfig,
+};
+
+/// Spaces after hashes, closing hashes and trailing spaces are normalized.
+#[test]
+fn case_1() {
+    let input = "##   Heading 2  ##\nLorem Ipsum\n### Heading 3 #\n#### Heading 4   \n##   \n## C#";
+
+    let mut options = get_example_options();
+    options.format_options.normalize_heading_syntax = true;
+    let config = FormatConfig::new(options);
+
+    let sections = get_sections(input, &config).unwrap();
+    let output = get_formatted_string(sections, &config).unwrap();
+    let expected_output = r#"## Heading 2
+Lorem Ipsum
+
+### Heading 3
+
+#### Heading 4
+
+
+
+##
+
+
+
+## C#"#;
+
+    assert_eq!(output, expected_output);
+}
+
+/// Titles of alternate headings are trimmed.
+#[test]
+fn case_2() {
+    let input = "Lorem Ipsum\n\n  Heading 2  \n---\n   Heading 3  ";
+
+    let mut options = get_example_options();
+    options.format_options.normalize_heading_syntax = true;
+    options.format_options.remove_heading_indentation = true;
+    let config = FormatConfig::new(options);
+
+    let sections = get_sections(input, &config).unwrap();
+    let output = get_formatted_string(sections, &config).unwrap();
+    let expected_output = "Lorem Ipsum\n\n\n\nHeading 2\n---\n   Heading 3";
+
+    assert_eq!(output, expected_output);
+}
+
+/// Headings are kept as they are by default.
+#[test]
+fn case_3() {
+    let input = "##   Heading 2  ##\nLorem Ipsum\n\n  Heading 2  \n---";
+
+    let config = FormatConfig::new(get_example_options());
+
+    let sections = get_sections(input, &config).unwrap();
+    let output = get_formatted_string(sections, &config).unwrap();
+    let expected_output = "##   Heading 2  ##\nLorem Ipsum\n\n\n\n  Heading 2  \n---";
+
+    assert_eq!(output, expected_output);
+}
+
+/// Indented hash headings are read, and hashes without a space after them are headings too.
+#[test]
+fn case_4() {
+    let input = "Lorem Ipsum\n  ##   Heading 2 ##\n##Heading 2\n- Item\n  ##Not a Heading";
+
+    let mut options = get_example_options();
+    options.format_options.normalize_heading_syntax = true;
+    options.format_options.remove_heading_indentation = true;
+    let config = FormatConfig::new(options);
+
+    let sections = get_sections(input, &config).unwrap();
+    let output = get_formatted_string(sections, &config).unwrap();
+    let expected_output =
+        "Lorem Ipsum\n\n\n\n## Heading 2\n\n\n\n## Heading 2\n- Item\n  ##Not a Heading";
+
+    assert_eq!(output, expected_output);
+}
+
+/// Hashes without a space after them are written with one space.
+#[test]
+fn case_5() {
+    let input = "##Title\n---\n###Heading 3##\n#######Not a Heading";
+
+    let mut options = get_example_options();
+    options.format_options.normalize_heading_syntax = true;
+    let config = FormatConfig::new(options);
+
+    let sections = get_sections(input, &config).unwrap();
+    let output = get_formatted_string(sections, &config).unwrap();
+    let expected_output = "## Title\n\n---\n\n### Heading 3##\n#######Not a Heading";
+
+    assert_eq!(output, expected_output);
+}
+
+/// Hashes without a space after them are not headings by default.
+#[test]
+fn case_6() {
+    let input = "## Heading 2\n##Not a Heading";
+
+    let config = FormatConfig::new(get_example_options());
+
+    let sections = get_sections(input, &config).unwrap();
+    let output = get_formatted_string(sections, &config).unwrap();
+    let expected_output = "## Heading 2\n##Not a Heading";
+
+    assert_eq!(output, expected_output);
+}
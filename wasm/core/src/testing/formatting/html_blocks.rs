@@ -0,0 +1,71 @@
+use crate::{
+    testing::get_example_config,
+    tools::{formatting::get_formatted_string, parsing::get_sections},
+};
+
+#[test]
+fn case_1() {
+    let input = r#"## Heading 2
+
+
+<div>
+Title
+===
+</div>
+
+
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+<table>
+<tr><td>Cell</td></tr>
+</table>"#;
+
+    let sections = get_sections(input, &get_example_config()).unwrap();
+    let output = get_formatted_string(sections, &get_example_config()).unwrap();
+    let expected_output = r#"## Heading 2
+<div>
+Title
+===
+</div>
+
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+
+<table>
+<tr><td>Cell</td></tr>
+</table>"#;
+
+    assert_eq!(output, expected_output);
+}
+
+#[test]
+fn case_3() {
+    // A hash heading inside an HTML block does not decide the top heading level.
+    let input = r#"```
+code
+```
+<custom-tag>
+# H
+
+## A
+text
+## B"#;
+
+    let sections = get_sections(input, &get_example_config()).unwrap();
+    let output = get_formatted_string(sections, &get_example_config()).unwrap();
+    let expected_output = r#"```
+code
+```
+
+<custom-tag>
+# H
+
+
+
+## A
+text
+
+
+
+## B"#;
+
+    assert_eq!(output, expected_output);
+}
@@ -0,0 +1,46 @@
+use crate::{
+    testing::get_example_config,
+    tools::{formatting::get_formatted_string, parsing::get_sections},
+};
+
+#[test]
+fn case_1() {
+    let input = r#"## Heading 2
+Lorem Ipsum
+%% formatto-ignore %%
+- Item
+
+
+- Item
+### Heading 3
+<!-- formatto-ignore-start -->
+# Title
+
+
+
+Lorem Ipsum
+<!-- formatto-ignore-end -->
+Lorem Ipsum"#;
+
+    let sections = get_sections(input, &get_example_config()).unwrap();
+    let output = get_formatted_string(sections, &get_example_config()).unwrap();
+    let expected_output = r#"## Heading 2
+Lorem Ipsum
+%% formatto-ignore %%
+- Item
+
+
+- Item
+
+### Heading 3
+<!-- formatto-ignore-start -->
+# Title
+
+
+
+Lorem Ipsum
+<!-- formatto-ignore-end -->
+Lorem Ipsum"#;
+
+    assert_eq!(output, expected_output);
+}
@@ -0,0 +1,38 @@
+use crate::{
+    testing::get_example_config,
+    tools::{formatting::get_formatted_string, parsing::get_sections},
+};
+
+#[test]
+fn case_1() {
+    let input = r#"## Heading 2
+
+$$
+a = b
+
+## c
+$$
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+$$
+x
+---
+$$"#;
+
+    let sections = get_sections(input, &get_example_config()).unwrap();
+    let output = get_formatted_string(sections, &get_example_config()).unwrap();
+    let expected_output = r#"## Heading 2
+$$
+a = b
+
+## c
+$$
+
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+
+$$
+x
+---
+$$"#;
+
+    assert_eq!(output, expected_output);
+}
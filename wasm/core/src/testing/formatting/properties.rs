@@ -0,0 +1,383 @@
+use crate::{
+    option_schema::{PropertyKeyOrder, PropertyListStyle, PropertyQuoteStyle},
+    testing::get_example_config,
+    tools::{formatting::get_formatted_string, parsing::get_sections},
+};
+
+#[test]
+fn case_1() {
+    let input = r#"---
+aliases:
+    - Test
+---
+
+## Heading 2
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+
+### Heading 3
+
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+
+#### Heading 4
+## Heading 2"#;
+
+    let sections = get_sections(input, &get_example_config()).unwrap();
+    let output = get_formatted_string(sections, &get_example_config()).unwrap();
+    let expected_output = r#"---
+aliases:
+    - Test
+---
+
+
+## Heading 2
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+
+### Heading 3
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+
+#### Heading 4
+
+
+
+## Heading 2"#;
+
+    assert_eq!(output, expected_output);
+}
+
+#[test]
+fn case_2() {
+    let input = r#"---
+aliases:
+  - Test
+---"#;
+
+    let sections = get_sections(input, &get_example_config()).unwrap();
+    let output = get_formatted_string(sections, &get_example_config()).unwrap();
+    let expected_output = r#"---
+aliases:
+  - Test
+---"#;
+
+    assert_eq!(output, expected_output);
+}
+
+#[test]
+fn case_3() {
+    let input = r#"+++
+title = "Lorem Ipsum"
++++
+## Heading 2
+Lorem Ipsum"#;
+
+    let sections = get_sections(input, &get_example_config()).unwrap();
+    let output = get_formatted_string(sections, &get_example_config()).unwrap();
+    let expected_output = r#"+++
+title = "Lorem Ipsum"
++++
+
+
+## Heading 2
+Lorem Ipsum"#;
+
+    assert_eq!(output, expected_output);
+}
+
+#[test]
+fn case_4() {
+    // Comments move with the keys below them.
+    let input = r#"---
+date: 2024-01-01
+# Tags of the note.
+tags:
+  - a
+  - b
+Title: Lorem Ipsum
+aliases: [Lorem]
+# The end.
+---"#;
+
+    let mut config = get_example_config();
+    config.options.property_options.key_order = PropertyKeyOrder::Alphabetical;
+    config.options.property_options.priority_keys = "title, aliases, tags".to_string();
+
+    let sections = get_sections(input, &config).unwrap();
+    let output = get_formatted_string(sections, &config).unwrap();
+    let expected_output = r#"---
+aliases: [Lorem]
+# Tags of the note.
+tags:
+  - a
+  - b
+date: 2024-01-01
+Title: Lorem Ipsum
+# The end.
+---"#;
+
+    assert_eq!(output, expected_output);
+}
+
+#[test]
+fn case_5() {
+    let input = r#"---
+aliases:
+- Lorem
+- "Ipsum, Dolor"
+tags:
+  - a
+  - b
+cssclasses: [wide, 'center'] # Comment
+nested:
+  - key: value
+---"#;
+
+    let mut config = get_example_config();
+    config.options.property_options.list_style = PropertyListStyle::Flow;
+
+    let sections = get_sections(input, &config).unwrap();
+    let output = get_formatted_string(sections, &config).unwrap();
+    let expected_output = r#"---
+aliases: [Lorem, "Ipsum, Dolor"]
+tags: [a, b]
+cssclasses: [wide, 'center'] # Comment
+nested:
+  - key: value
+---"#;
+
+    assert_eq!(output, expected_output);
+
+    config.options.property_options.list_style = PropertyListStyle::Block;
+
+    let sections = get_sections(input, &config).unwrap();
+    let output = get_formatted_string(sections, &config).unwrap();
+    let expected_output = r#"---
+aliases:
+- Lorem
+- "Ipsum, Dolor"
+tags:
+  - a
+  - b
+cssclasses: [wide, 'center'] # Comment
+nested:
+  - key: value
+---"#;
+
+    assert_eq!(output, expected_output);
+
+    let input = r#"---
+tags: [a, "b"]
+empty: []
+---"#;
+
+    let sections = get_sections(input, &config).unwrap();
+    let output = get_formatted_string(sections, &config).unwrap();
+    let expected_output = r#"---
+tags:
+  - a
+  - "b"
+empty: []
+---"#;
+
+    assert_eq!(output, expected_output);
+}
+
+#[test]
+fn case_6() {
+    let input = r#"---
+title: 'It''s "Lorem"' # Comment
+author: "Lorem \\ Ipsum"
+escaped: "Lorem\tIpsum"
+plain: It's Lorem
+tags:
+  - 'a'
+  - "b"
+---"#;
+
+    let mut config = get_example_config();
+    config.options.property_options.quote_style = PropertyQuoteStyle::Double;
+
+    let sections = get_sections(input, &config).unwrap();
+    let output = get_formatted_string(sections, &config).unwrap();
+    let expected_output = r#"---
+title: "It's \"Lorem\"" # Comment
+author: "Lorem \\ Ipsum"
+escaped: "Lorem\tIpsum"
+plain: It's Lorem
+tags:
+  - "a"
+  - "b"
+---"#;
+
+    assert_eq!(output, expected_output);
+
+    config.options.property_options.quote_style = PropertyQuoteStyle::Single;
+
+    let sections = get_sections(input, &config).unwrap();
+    let output = get_formatted_string(sections, &config).unwrap();
+    let expected_output = r#"---
+title: 'It''s "Lorem"' # Comment
+author: 'Lorem \ Ipsum'
+escaped: "Lorem\tIpsum"
+plain: It's Lorem
+tags:
+  - 'a'
+  - 'b'
+---"#;
+
+    assert_eq!(output, expected_output);
+}
+
+#[test]
+fn case_7() {
+    // Blank lines in block scalars are kept.
+    let input = r#"---
+title: Lorem Ipsum
+
+tags:
+  - a
+
+  - b
+
+description: |
+  Lorem
+
+  Ipsum
+
+---"#;
+
+    let mut config = get_example_config();
+    config.options.property_options.remove_blank_lines = true;
+
+    let sections = get_sections(input, &config).unwrap();
+    let output = get_formatted_string(sections, &config).unwrap();
+    let expected_output = r#"---
+title: Lorem Ipsum
+tags:
+  - a
+  - b
+description: |
+  Lorem
+
+  Ipsum
+---"#;
+
+    assert_eq!(output, expected_output);
+}
+
+#[test]
+fn case_8() {
+    // Properties that cannot be read line by line are kept.
+    let input = r#"---
+? complex key
+: value
+title: Lorem Ipsum
+---"#;
+
+    let mut config = get_example_config();
+    config.options.property_options.key_order = PropertyKeyOrder::Alphabetical;
+    config.options.property_options.remove_blank_lines = true;
+
+    let sections = get_sections(input, &config).unwrap();
+    let output = get_formatted_string(sections, &config).unwrap();
+
+    assert_eq!(output, input);
+}
+
+#[test]
+fn case_9() {
+    let input = r##"---
+tags: "#Lorem"
+tags2: a, b
+aliases: Lorem Ipsum, Dolor
+cssclasses: wide center
+---
+## Heading 2"##;
+
+    let mut config = get_example_config();
+    config.options.property_options.normalize_list_properties = true;
+
+    let sections = get_sections(input, &config).unwrap();
+    let output = get_formatted_string(sections, &config).unwrap();
+    let expected_output = r##"---
+tags:
+  - "Lorem"
+tags2: a, b
+aliases:
+  - Lorem Ipsum
+  - Dolor
+cssclasses:
+  - wide
+  - center
+---
+
+
+## Heading 2"##;
+
+    assert_eq!(output, expected_output);
+}
+
+#[test]
+fn case_10() {
+    let input = r##"---
+tags:
+    - "#Project"
+    - b
+    - project
+    - '#a'
+aliases: [Lorem, Lorem, "Ipsum"]
+---"##;
+
+    let mut config = get_example_config();
+    config.options.property_options.normalize_list_properties = true;
+    config.options.property_options.lowercase_tags = true;
+    config.options.property_options.sort_list_properties = true;
+
+    let sections = get_sections(input, &config).unwrap();
+    let output = get_formatted_string(sections, &config).unwrap();
+    let expected_output = r##"---
+tags:
+    - 'a'
+    - b
+    - "project"
+aliases:
+  - "Ipsum"
+  - Lorem
+---"##;
+
+    assert_eq!(output, expected_output);
+
+    config.options.property_options.list_style = PropertyListStyle::Flow;
+    config.options.property_options.quote_style = PropertyQuoteStyle::Double;
+
+    let sections = get_sections(input, &config).unwrap();
+    let output = get_formatted_string(sections, &config).unwrap();
+    let expected_output = r##"---
+tags: ["a", b, "project"]
+aliases: ["Ipsum", Lorem]
+---"##;
+
+    assert_eq!(output, expected_output);
+}
+
+#[test]
+fn case_11() {
+    // Tags in flow lists can start with `#`.
+    let input = r##"---
+tags: [#B, a, "#a", b]
+aliases: [#a, "b"
+---"##;
+
+    let mut config = get_example_config();
+    config.options.property_options.normalize_list_properties = true;
+    config.options.property_options.lowercase_tags = true;
+
+    let sections = get_sections(input, &config).unwrap();
+    let output = get_formatted_string(sections, &config).unwrap();
+    let expected_output = r##"---
+tags:
+  - b
+  - a
+aliases: [#a, "b"
+---"##;
+
+    assert_eq!(output, expected_output);
+}
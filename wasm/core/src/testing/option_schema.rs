@@ -0,0 +1,113 @@
+use serde_json::json;
+
+use crate::{option_schema::ThematicBreakStyle, FormatError, PluginOptions};
+
+#[test]
+fn legacy_string_values() {
+    let value = json!({
+        "headingGaps": {
+            "beforeTopLevelHeadings": "4",
+            "beforeFirstSubHeading": "0",
+            "beforeSubHeadings": "2"
+        },
+        "formatOptions": {
+            "insertNewline": false
+        }
+    });
+
+    let options = PluginOptions::from_value(&value).unwrap();
+
+    assert_eq!(options.heading_gaps.before_top_level_headings, 4);
+    assert_eq!(options.heading_gaps.before_first_sub_heading, 0);
+    assert_eq!(options.heading_gaps.before_sub_headings, 2);
+    assert!(!options.format_options.insert_newline);
+}
+
+#[test]
+fn number_values() {
+    let value = json!({
+        "otherGaps": {
+            "afterProperties": 0,
+            "beforeCodeBlocks": 3.0
+        }
+    });
+
+    let options = PluginOptions::from_value(&value).unwrap();
+
+    assert_eq!(options.other_gaps.after_properties, 0);
+    assert_eq!(options.other_gaps.before_code_blocks, 3);
+}
+
+#[test]
+fn missing_values() {
+    let options = PluginOptions::from_value(&json!({ "otherGaps": {} })).unwrap();
+
+    assert_eq!(options.heading_gaps.before_top_level_headings, 3);
+    assert_eq!(options.other_gaps.before_contents_after_code_blocks, 1);
+    assert!(options.format_options.insert_newline);
+}
+
+#[test]
+fn enum_values() {
+    let value = json!({
+        "formatOptions": {
+            "thematicBreakStyle": "underscores"
+        }
+    });
+
+    let options = PluginOptions::from_value(&value).unwrap();
+
+    assert_eq!(
+        options.format_options.thematic_break_style,
+        ThematicBreakStyle::Underscores
+    );
+}
+
+#[test]
+fn invalid_value_1() {
+    let value = json!({
+        "otherGaps": {
+            "beforeContents": "-1"
+        }
+    });
+
+    let expected_output = FormatError::InvalidOption {
+        field: "otherGaps.beforeContents".to_string(),
+        value: "-1".to_string(),
+    };
+
+    assert_eq!(
+        PluginOptions::from_value(&value).unwrap_err(),
+        expected_output
+    );
+}
+
+#[test]
+fn invalid_value_2() {
+    let value = json!({
+        "headingGaps": {
+            "beforeSubHeadings": 1.5
+        }
+    });
+
+    let expected_output = FormatError::InvalidOption {
+        field: "headingGaps.beforeSubHeadings".to_string(),
+        value: "1.5".to_string(),
+    };
+
+    assert_eq!(
+        PluginOptions::from_value(&value).unwrap_err(),
+        expected_output
+    );
+}
+
+#[test]
+fn invalid_value_3() {
+    let value = json!({
+        "headingGaps": {
+            "beforeSubHeadings": ""
+        }
+    });
+
+    assert!(PluginOptions::from_value(&value).is_err());
+}
@@ -0,0 +1,163 @@
+use crate::{format, format_edits, testing::get_example_config, FormatError};
+
+#[test]
+fn flow_map() {
+    let input = r#"---
+formatto: { beforeTopLevelHeadings: 1, beforeContents: "1" }
+---
+# Heading 1
+Lorem Ipsum
+# Heading 1"#;
+
+    let expected_output = r#"---
+formatto: { beforeTopLevelHeadings: 1, beforeContents: "1" }
+---
+
+
+# Heading 1
+
+Lorem Ipsum
+
+# Heading 1"#;
+
+    assert_eq!(
+        format(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn block_map() {
+    let input = r#"---
+formatto:
+  afterProperties: 0 # Comment
+  thematicBreakStyle: asterisks
+  insertNewline: true
+---
+Lorem Ipsum
+
+---"#;
+
+    let expected_output = r#"---
+formatto:
+  afterProperties: 0 # Comment
+  thematicBreakStyle: asterisks
+  insertNewline: true
+---
+Lorem Ipsum
+
+***
+"#;
+
+    assert_eq!(
+        format(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn off() {
+    let input = r#"---
+formatto: off
+---
+# Heading 1
+Lorem Ipsum
+
+
+
+# Heading 1"#;
+
+    assert_eq!(format(input, &get_example_config()).unwrap(), input);
+    assert_eq!(
+        format_edits(input, &get_example_config()).unwrap(),
+        Vec::new()
+    );
+}
+
+#[test]
+fn crlf_1() {
+    // Properties of documents with CRLF line breaks are read too.
+    let input =
+        "---\r\nformatto:\r\n  beforeTopLevelHeadings: 0\r\n---\r\n# Heading 1\r\n# Heading 1";
+
+    let expected_output =
+        "---\r\nformatto:\r\n  beforeTopLevelHeadings: 0\r\n---\r\n\n\n# Heading 1\r\n# Heading 1";
+
+    assert_eq!(
+        format(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+
+    let input = "---\r\nformatto: off\r\n---\r\n# Heading 1\r\n# Heading 1";
+
+    assert_eq!(format(input, &get_example_config()).unwrap(), input);
+}
+
+#[test]
+fn invalid_option_1() {
+    let input = r#"---
+formatto: { beforeEverything: 1 }
+---"#;
+
+    let expected_output = FormatError::InvalidOption {
+        field: "formatto.beforeEverything".to_string(),
+        value: "1".to_string(),
+    };
+
+    assert_eq!(
+        format(input, &get_example_config()).unwrap_err(),
+        expected_output
+    );
+}
+
+#[test]
+fn invalid_option_2() {
+    let input = r#"---
+formatto:
+  beforeSubHeadings: -1
+---"#;
+
+    let expected_output = FormatError::InvalidOption {
+        field: "formatto.beforeSubHeadings".to_string(),
+        value: "-1".to_string(),
+    };
+
+    assert_eq!(
+        format(input, &get_example_config()).unwrap_err(),
+        expected_output
+    );
+}
+
+#[test]
+fn invalid_option_3() {
+    let input = r#"---
+formatto: sometimes
+---"#;
+
+    let expected_output = FormatError::InvalidOption {
+        field: "formatto".to_string(),
+        value: "sometimes".to_string(),
+    };
+
+    assert_eq!(
+        format(input, &get_example_config()).unwrap_err(),
+        expected_output
+    );
+}
+
+#[test]
+fn invalid_option_4() {
+    let input = r#"---
+formatto: { insertNewline: true, headingStyle: sideways }
+---"#;
+
+    let expected_output = FormatError::InvalidOption {
+        field: "formatto.headingStyle".to_string(),
+        value: "sideways".to_string(),
+    };
+
+    assert_eq!(
+        format(input, &get_example_config()).unwrap_err(),
+        expected_output
+    );
+}
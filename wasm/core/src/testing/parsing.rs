@@ -1,8 +1,16 @@
 mod get_sections {
+    mod blockquotes;
     mod code_blocks;
+    mod comments;
     mod contents;
     mod general;
+    mod html_blocks;
+    mod ignores;
+    mod lists;
+    mod math_blocks;
     mod properties;
+    mod tables;
+    mod thematic_breaks;
 
     mod headings {
         mod alternate_headings;
@@ -0,0 +1,68 @@
+use crate::{
+    testing::get_example_config,
+    tools::{
+        parsing::get_sections,
+        tokens::{Blockquote, HeadingLevel, MarkdownSection},
+    },
+};
+
+#[test]
+fn case_1() {
+    let input = r#"## Heading 2
+> [!note] Title
+> ## Heading
+> Lorem Ipsum
+>
+> > Nested
+
+Lorem Ipsum"#;
+
+    let expected_output = vec![
+        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
+        MarkdownSection::Blockquote(Blockquote {
+            text: "> [!note] Title\n> ## Heading\n> Lorem Ipsum\n>\n> > Nested".to_string(),
+            prefix: "> ".to_string(),
+            is_callout: true,
+            sections: vec![
+                MarkdownSection::Content("[!note] Title".to_string()),
+                MarkdownSection::Heading(HeadingLevel::Top("## Heading".to_string())),
+                MarkdownSection::Content("Lorem Ipsum".to_string()),
+                MarkdownSection::Blockquote(Blockquote {
+                    text: "> Nested".to_string(),
+                    prefix: "> ".to_string(),
+                    is_callout: false,
+                    sections: vec![MarkdownSection::Content("Nested".to_string())],
+                }),
+            ],
+        }),
+        MarkdownSection::Content("Lorem Ipsum".to_string()),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn case_2() {
+    // Blockquotes cannot have properties.
+    let input = r#">---
+>Lorem Ipsum
+>---"#;
+
+    let expected_output = vec![MarkdownSection::Blockquote(Blockquote {
+        text: ">---\n>Lorem Ipsum\n>---".to_string(),
+        prefix: ">".to_string(),
+        is_callout: false,
+        sections: vec![
+            MarkdownSection::ThematicBreak("---".to_string()),
+            MarkdownSection::Content("Lorem Ipsum\n---".to_string()),
+        ],
+    })];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
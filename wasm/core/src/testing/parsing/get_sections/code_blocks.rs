@@ -0,0 +1,276 @@
+use crate::{
+    testing::get_example_config,
+    tools::{
+        parsing::get_sections,
+        tokens::{Blockquote, HeadingLevel, MarkdownSection},
+    },
+};
+
+#[test]
+fn case_1() {
+    let input = r#"## Heading 2
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+
+#### Heading 4
+```rust
+fn main(
+println!(\"Hello World\");
+) {}
+```"#;
+
+    let expected_output = vec![
+        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
+        MarkdownSection::Content(
+            "Lorem Ipsum is simply dummy text of the printing and typesetting industry."
+                .to_string(),
+        ),
+        MarkdownSection::Heading(HeadingLevel::FirstSub("#### Heading 4".to_string())),
+        MarkdownSection::Code(
+            r#"```rust
+fn main(
+println!(\"Hello World\");
+) {}
+```"#
+                .to_string(),
+        ),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn case_2() {
+    let input = r#"## Heading 2
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+
+#### Heading 4
+`````rust
+fn main(
+println!(\"Hello World\");
+) {}
+`````"#;
+
+    let expected_output = vec![
+        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
+        MarkdownSection::Content(
+            "Lorem Ipsum is simply dummy text of the printing and typesetting industry."
+                .to_string(),
+        ),
+        MarkdownSection::Heading(HeadingLevel::FirstSub("#### Heading 4".to_string())),
+        MarkdownSection::Code(
+            r#"`````rust
+fn main(
+println!(\"Hello World\");
+) {}
+`````"#
+                .to_string(),
+        ),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn case_3() {
+    let input = r#"## Heading 2
+~~~bash
+# comment
+```
+# comment
+~~~
+Lorem Ipsum is simply dummy text of the printing and typesetting industry."#;
+
+    let expected_output = vec![
+        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
+        MarkdownSection::Code(
+            r#"~~~bash
+# comment
+```
+# comment
+~~~"#
+                .to_string(),
+        ),
+        MarkdownSection::Content(
+            "Lorem Ipsum is simply dummy text of the printing and typesetting industry."
+                .to_string(),
+        ),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn case_4() {
+    let input = r#"## Heading 2
+~~~~~
+~~~
+# comment
+~~~
+~~~~~"#;
+
+    let expected_output = vec![
+        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
+        MarkdownSection::Code(
+            r#"~~~~~
+~~~
+# comment
+~~~
+~~~~~"#
+                .to_string(),
+        ),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn case_5() {
+    let input = r#"## Heading 2
+1. Install
+   ```bash
+   # comment
+   ```
+2. Run
+
+   ```c
+   #include <stdio.h>
+   ```"#;
+
+    let expected_output = vec![
+        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
+        // Code blocks in list items are a part of the list.
+        MarkdownSection::List(
+            r#"1. Install
+   ```bash
+   # comment
+   ```
+2. Run
+
+   ```c
+   #include <stdio.h>
+   ```"#
+                .to_string(),
+        ),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn case_6() {
+    let input = r#"## Heading 2
+> ```bash
+> # comment
+> ```
+- ```python
+  # comment
+  ```"#;
+
+    let expected_output = vec![
+        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
+        MarkdownSection::Blockquote(Blockquote {
+            text: "> ```bash\n> # comment\n> ```".to_string(),
+            prefix: "> ".to_string(),
+            is_callout: false,
+            sections: vec![MarkdownSection::Code("```bash\n# comment\n```".to_string())],
+        }),
+        MarkdownSection::List(
+            r#"- ```python
+  # comment
+  ```"#
+                .to_string(),
+        ),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
+
+/// Lines indented four spaces outside of lists are not fences.
+#[test]
+fn case_7() {
+    let input = r#"## Heading 2
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+
+    ```
+## Heading 2"#;
+
+    let expected_output = vec![
+        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
+        MarkdownSection::Content(
+            r#"Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+
+    ```"#
+                .to_string(),
+        ),
+        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
+
+/// Lines with fence characters and text do not close code blocks.
+#[test]
+fn case_8() {
+    let input = r#"```md
+Use `code` or ```code``` for inline code.
+``` not closed
+# Heading in a code block
+`````   
+## Heading 2"#;
+
+    let expected_output = vec![
+        MarkdownSection::Code(
+            r#"```md
+Use `code` or ```code``` for inline code.
+``` not closed
+# Heading in a code block
+`````   "#
+                .to_string(),
+        ),
+        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn invalid_input_1() {
+    let input = r#"```
+code
+SPACE```"#;
+    let sections = get_sections(input, &get_example_config());
+    assert!(sections.is_err());
+}
+
+#[test]
+fn invalid_input_2() {
+    let input = r#"~~~
+code
+```"#;
+    let sections = get_sections(input, &get_example_config());
+    assert!(sections.is_err());
+}
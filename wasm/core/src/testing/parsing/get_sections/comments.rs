@@ -0,0 +1,141 @@
+use crate::{
+    testing::get_example_config,
+    tools::{
+        parsing::get_sections,
+        tokens::{HeadingLevel, MarkdownSection},
+    },
+};
+
+#[test]
+fn case_1() {
+    let input = r#"## Heading 2
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+%%
+## Commented Heading
+
+
+Lorem Ipsum
+---
+%%
+Lorem Ipsum"#;
+
+    let expected_output = vec![
+        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
+        MarkdownSection::Content(
+            "Lorem Ipsum is simply dummy text of the printing and typesetting industry."
+                .to_string(),
+        ),
+        MarkdownSection::Comment("%%\n## Commented Heading\n\n\nLorem Ipsum\n---\n%%".to_string()),
+        MarkdownSection::Content("Lorem Ipsum".to_string()),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn case_2() {
+    let input = r#"## Heading 2
+<!-- Comment -->
+<!--
+```rust
+## Commented Heading
+-->
+%% Comment %%"#;
+
+    let expected_output = vec![
+        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
+        MarkdownSection::Comment("<!-- Comment -->".to_string()),
+        MarkdownSection::Comment("<!--\n```rust\n## Commented Heading\n-->".to_string()),
+        MarkdownSection::Comment("%% Comment %%".to_string()),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn case_3() {
+    // Comment markers in code blocks are not comments.
+    let input = r#"```
+%%
+```
+## Heading 2"#;
+
+    let expected_output = vec![
+        MarkdownSection::Code("```\n%%\n```".to_string()),
+        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn case_4() {
+    // A fence inside a comment does not open a code block.
+    let input = r#"%%
+```
+%%
+# T
+text
+## S"#;
+
+    let expected_output = vec![
+        MarkdownSection::Comment("%%\n```\n%%".to_string()),
+        MarkdownSection::Heading(HeadingLevel::Top("# T".to_string())),
+        MarkdownSection::Content("text".to_string()),
+        MarkdownSection::Heading(HeadingLevel::FirstSub("## S".to_string())),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn case_5() {
+    // Comments opened in the middle of a line are a part of the content.
+    let input = r#"## Heading 2
+Lorem Ipsum %%
+# Commented Heading
+%%
+## Heading 2"#;
+
+    let expected_output = vec![
+        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
+        MarkdownSection::Content("Lorem Ipsum %%\n# Commented Heading\n%%".to_string()),
+        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn case_6() {
+    // Markers are read from left to right, so the third marker opens a comment again.
+    let input = r#"%% a %% b %%
+# Commented Heading
+%%
+## Heading 2"#;
+
+    let expected_output = vec![
+        MarkdownSection::Comment("%% a %% b %%\n# Commented Heading\n%%".to_string()),
+        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
@@ -1,5 +1,5 @@
 use crate::{
-    testing::{get_example_preferences, setup},
+    testing::get_example_config,
     tools::{
         parsing::get_sections,
         tokens::{HeadingLevel, MarkdownSection},
@@ -9,8 +9,6 @@ use crate::{
 /// Contents only.
 #[test]
 fn case_1() {
-    setup();
-
     let input = r#"Lorem Ipsum is simply dummy text of the printing and typesetting industry.
 Lorem Ipsum is simply dummy text of the printing and typesetting industry.
 Lorem Ipsum is simply dummy text of the printing and typesetting industry."#;
@@ -23,7 +21,7 @@ Lorem Ipsum is simply dummy text of the printing and typesetting industry."#
     )];
 
     assert_eq!(
-        get_sections(input, &get_example_preferences()).unwrap(),
+        get_sections(input, &get_example_config()).unwrap(),
         expected_output
     );
 }
@@ -31,8 +29,6 @@ Lorem Ipsum is simply dummy text of the printing and typesetting industry."#
 /// Contents with escape character line break syntax.
 #[test]
 fn case_2() {
-    setup();
-
     let input = r#"## Heading 2
 Lorem Ipsum is simply dummy text of the printing and typesetting industry.
 
@@ -60,7 +56,7 @@ Lorem Ipsum is simply dummy text of the printing and typesetting industry."#
     ];
 
     assert_eq!(
-        get_sections(input, &get_example_preferences()).unwrap(),
+        get_sections(input, &get_example_config()).unwrap(),
         expected_output
     );
 }
@@ -0,0 +1,12 @@
+use crate::{testing::get_example_config, tools::parsing::get_sections};
+
+#[test]
+fn empty_input() {
+    let input = "";
+    let expected_output = Vec::new();
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
@@ -1,5 +1,5 @@
 use crate::{
-    testing::{get_example_preferences, setup},
+    testing::get_example_config,
     tools::{
         parsing::get_sections,
         tokens::{HeadingLevel, MarkdownSection},
@@ -8,8 +8,6 @@ use crate::{
 
 #[test]
 fn case_1() {
-    setup();
-
     let input = r#"Heading 1
 ====
 
@@ -23,15 +21,13 @@ fn case_1() {
     ];
 
     assert_eq!(
-        get_sections(input, &get_example_preferences()).unwrap(),
+        get_sections(input, &get_example_config()).unwrap(),
         expected_output
     );
 }
 
 #[test]
 fn case_2() {
-    setup();
-
     let input = r#"Heading 1
 ====
 
@@ -54,15 +50,13 @@ Heading 2
     ];
 
     assert_eq!(
-        get_sections(input, &get_example_preferences()).unwrap(),
+        get_sections(input, &get_example_config()).unwrap(),
         expected_output
     );
 }
 
 #[test]
 fn case_3() {
-    setup();
-
     let input = r#"## Heading 2
 
 ### Heading 3
@@ -99,15 +93,13 @@ Content
     ];
 
     assert_eq!(
-        get_sections(input, &get_example_preferences()).unwrap(),
+        get_sections(input, &get_example_config()).unwrap(),
         expected_output
     );
 }
 
 #[test]
 fn case_4() {
-    setup();
-
     let input = r#"Lorem Ipsum is simply dummy text of the printing and typesetting industry.
 Lorem Ipsum is simply dummy text of the printing and typesetting industry.
 
@@ -128,15 +120,13 @@ Content
     ];
 
     assert_eq!(
-        get_sections(input, &get_example_preferences()).unwrap(),
+        get_sections(input, &get_example_config()).unwrap(),
         expected_output
     );
 }
 
 #[test]
 fn case_5() {
-    setup();
-
     let input = r#"## Heading 2
 aabbcc
 ===
@@ -154,15 +144,13 @@ Content
     ];
 
     assert_eq!(
-        get_sections(input, &get_example_preferences()).unwrap(),
+        get_sections(input, &get_example_config()).unwrap(),
         expected_output
     );
 }
 
 #[test]
 fn case_6() {
-    setup();
-
     let input = r#"## Heading 2
 INVALID
 INVALID
@@ -177,15 +165,13 @@ Content
     ];
 
     assert_eq!(
-        get_sections(input, &get_example_preferences()).unwrap(),
+        get_sections(input, &get_example_config()).unwrap(),
         expected_output
     );
 }
 
 #[test]
 fn case_7() {
-    setup();
-
     let input = r#"## Heading 2
 
 ### Heading 3
@@ -228,7 +214,7 @@ Content
     ];
 
     assert_eq!(
-        get_sections(input, &get_example_preferences()).unwrap(),
+        get_sections(input, &get_example_config()).unwrap(),
         expected_output
     );
 }
@@ -1,5 +1,5 @@
 use crate::{
-    testing::{get_example_preferences, setup},
+    testing::get_example_config,
     tools::{
         parsing::get_sections,
         tokens::{HeadingLevel, MarkdownSection},
@@ -9,8 +9,6 @@ use crate::{
 // Only one level of headings.
 #[test]
 fn case_1() {
-    setup();
-
     let input = r#"## Heading 2
 Lorem Ipsum is simply dummy text of the printing and typesetting industry.
 ## Heading 2"#;
@@ -25,15 +23,13 @@ Lorem Ipsum is simply dummy text of the printing and typesetting industry.
     ];
 
     assert_eq!(
-        get_sections(input, &get_example_preferences()).unwrap(),
+        get_sections(input, &get_example_config()).unwrap(),
         expected_output
     );
 }
 
 #[test]
 fn case_2() {
-    setup();
-
     let input = r#"## Heading 2
 ## Heading 2
 ## Heading 2"#;
@@ -45,15 +41,13 @@ fn case_2() {
     ];
 
     assert_eq!(
-        get_sections(input, &get_example_preferences()).unwrap(),
+        get_sections(input, &get_example_config()).unwrap(),
         expected_output
     );
 }
 
 #[test]
 fn case_3() {
-    setup();
-
     let input = r#"## Heading 2
 ### Heading 3
 #### Heading 4"#;
@@ -65,15 +59,13 @@ fn case_3() {
     ];
 
     assert_eq!(
-        get_sections(input, &get_example_preferences()).unwrap(),
+        get_sections(input, &get_example_config()).unwrap(),
         expected_output
     );
 }
 
 #[test]
 fn case_4() {
-    setup();
-
     let input = r#"## Heading 2
 Lorem Ipsum is simply dummy text of the printing and typesetting industry.
 
@@ -102,7 +94,7 @@ Lorem Ipsum is simply dummy text of the printing and typesetting industry."#;
     ];
 
     assert_eq!(
-        get_sections(input, &get_example_preferences()).unwrap(),
+        get_sections(input, &get_example_config()).unwrap(),
         expected_output
     );
 }
@@ -110,8 +102,6 @@ Lorem Ipsum is simply dummy text of the printing and typesetting industry."#;
 /// Random line breaks.
 #[test]
 fn case_5() {
-    setup();
-
     let input = r#"## Heading 2
 Lorem Ipsum is simply dummy text of the printing and typesetting industry.
 
@@ -138,7 +128,7 @@ Lorem Ipsum is simply dummy text of the printing and typesetting industry.
     ];
 
     assert_eq!(
-        get_sections(input, &get_example_preferences()).unwrap(),
+        get_sections(input, &get_example_config()).unwrap(),
         expected_output
     );
 }
@@ -146,8 +136,6 @@ Lorem Ipsum is simply dummy text of the printing and typesetting industry.
 // Two levels of hash headings.
 #[test]
 fn case_6() {
-    setup();
-
     let input = r#"## Heading 2
 Lorem Ipsum is simply dummy text of the printing and typesetting industry.
 ### Heading 3
@@ -167,33 +155,29 @@ Lorem Ipsum is simply dummy text of the printing and typesetting industry."#;
     ];
 
     assert_eq!(
-        get_sections(input, &get_example_preferences()).unwrap(),
+        get_sections(input, &get_example_config()).unwrap(),
         expected_output
     );
 }
 
 #[test]
 fn case_7() {
-    setup();
-
     let input = r#"## Heading 2
 - "#;
 
     let expected_output = vec![
         MarkdownSection::Heading(HeadingLevel::Top(r#"## Heading 2"#.to_string())),
-        MarkdownSection::Content("-".to_string()),
+        MarkdownSection::List("-".to_string()),
     ];
 
     assert_eq!(
-        get_sections(input, &get_example_preferences()).unwrap(),
+        get_sections(input, &get_example_config()).unwrap(),
         expected_output
     );
 }
 
 #[test]
 fn case_8() {
-    setup();
-
     let input = r#"### Heading 3
 ## Heading 2
 - "#;
@@ -201,19 +185,42 @@ fn case_8() {
     let expected_output = vec![
         MarkdownSection::Heading(HeadingLevel::FirstSub("### Heading 3".to_string())),
         MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
-        MarkdownSection::Content(r#"-"#.to_string()),
+        MarkdownSection::List(r#"-"#.to_string()),
     ];
 
     assert_eq!(
-        get_sections(input, &get_example_preferences()).unwrap(),
+        get_sections(input, &get_example_config()).unwrap(),
         expected_output
     );
 }
 
+/// Headings can be indented up to three spaces, unless they are in list items.
 #[test]
-fn hash_headings_without_title_names() {
-    setup();
+fn case_9() {
+    let input = r#"Lorem Ipsum
+   ## Heading 2
+ ### Heading 3
+    ## Not a Heading
+- Item
+  ## Not a Heading
+#Tag"#;
+
+    let expected_output = vec![
+        MarkdownSection::Content("Lorem Ipsum".to_string()),
+        MarkdownSection::Heading(HeadingLevel::Top("   ## Heading 2".to_string())),
+        MarkdownSection::Heading(HeadingLevel::FirstSub(" ### Heading 3".to_string())),
+        MarkdownSection::Content("    ## Not a Heading".to_string()),
+        MarkdownSection::List("- Item\n  ## Not a Heading\n#Tag".to_string()),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
 
+#[test]
+fn hash_headings_without_title_names() {
     let input = r#"#
 ##
 ##
@@ -242,15 +249,13 @@ fn hash_headings_without_title_names() {
     ];
 
     assert_eq!(
-        get_sections(input, &get_example_preferences()).unwrap(),
+        get_sections(input, &get_example_config()).unwrap(),
         expected_output
     );
 }
 
 #[test]
 fn invalid_input_1() {
-    setup();
-
     let input = r#"##Heading 2
 ###Heading 3
 ####Heading 4"#;
@@ -263,7 +268,7 @@ fn invalid_input_1() {
     )];
 
     assert_eq!(
-        get_sections(input, &get_example_preferences()).unwrap(),
+        get_sections(input, &get_example_config()).unwrap(),
         expected_output
     );
 }
@@ -0,0 +1,146 @@
+use crate::{
+    testing::get_example_config,
+    tools::{
+        parsing::get_sections,
+        tokens::{HeadingLevel, MarkdownSection},
+    },
+};
+
+#[test]
+fn case_1() {
+    let input = r#"## Heading 2
+<details>
+<summary>Summary</summary>
+Title
+---
+# Not a Heading
+</details>
+
+Lorem Ipsum"#;
+
+    let expected_output = vec![
+        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
+        MarkdownSection::Html(
+            "<details>\n<summary>Summary</summary>\nTitle\n---\n# Not a Heading\n</details>"
+                .to_string(),
+        ),
+        MarkdownSection::Content("Lorem Ipsum".to_string()),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn case_2() {
+    let input = r#"## Heading 2
+<pre>
+# Not a Heading
+
+
+</PRE>
+<?php echo 1; ?>
+<!DOCTYPE html>
+<![CDATA[
+x
+]]>"#;
+
+    let expected_output = vec![
+        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
+        MarkdownSection::Html("<pre>\n# Not a Heading\n\n\n</PRE>".to_string()),
+        MarkdownSection::Html("<?php echo 1; ?>".to_string()),
+        MarkdownSection::Html("<!DOCTYPE html>".to_string()),
+        MarkdownSection::Html("<![CDATA[\nx\n]]>".to_string()),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn case_3() {
+    // Complete tags of other elements cannot interrupt a paragraph.
+    let input = r#"## Heading 2
+<span class="a">
+# Not a Heading
+
+Lorem Ipsum
+<span class="a">
+## Heading 2"#;
+
+    let expected_output = vec![
+        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
+        MarkdownSection::Html("<span class=\"a\">\n# Not a Heading".to_string()),
+        MarkdownSection::Content("Lorem Ipsum\n<span class=\"a\">".to_string()),
+        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn case_4() {
+    // Not HTML blocks.
+    let input = r#"## Heading 2
+<span>Lorem Ipsum</span>
+<divider>
+    <div>
+a < b"#;
+
+    let expected_output = vec![
+        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
+        MarkdownSection::Content(
+            "<span>Lorem Ipsum</span>\n<divider>\n    <div>\na < b".to_string(),
+        ),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn case_5() {
+    // Fences inside HTML blocks are a part of them.
+    let input = r#"<pre>
+```py
+x
+```"#;
+
+    let expected_output = vec![MarkdownSection::Html("<pre>\n```py\nx\n```".to_string())];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn case_6() {
+    let input = r#"text
+<pre>
+```
+x
+```
+</pre>
+after"#;
+
+    let expected_output = vec![
+        MarkdownSection::Content("text".to_string()),
+        MarkdownSection::Html("<pre>\n```\nx\n```\n</pre>".to_string()),
+        MarkdownSection::Content("after".to_string()),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
@@ -0,0 +1,117 @@
+use crate::{
+    testing::get_example_config,
+    tools::{
+        parsing::get_sections,
+        tokens::{HeadingLevel, MarkdownSection},
+    },
+};
+
+#[test]
+fn case_1() {
+    let input = r#"## Heading 2
+<!-- formatto-ignore-start -->
++---+
+| A |
+
+
++---+
+## Not a Heading
+<!-- formatto-ignore-end -->
+Lorem Ipsum"#;
+
+    let expected_output = vec![
+        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
+        MarkdownSection::Ignored(
+            "<!-- formatto-ignore-start -->\n+---+\n| A |\n\n\n+---+\n## Not a Heading\n<!-- formatto-ignore-end -->"
+                .to_string(),
+        ),
+        MarkdownSection::Content("Lorem Ipsum".to_string()),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn case_2() {
+    // `formatto-ignore` ignores the next section.
+    let input = r#"%% formatto-ignore %%
+
+Roses are red,
+    violets are blue.
+
+Lorem Ipsum
+<!-- formatto-ignore -->
+```
+Code
+
+
+```
+%% formatto-ignore %%"#;
+
+    let expected_output = vec![
+        MarkdownSection::Ignored(
+            "%% formatto-ignore %%\n\nRoses are red,\n    violets are blue.".to_string(),
+        ),
+        MarkdownSection::Content("Lorem Ipsum".to_string()),
+        MarkdownSection::Ignored("<!-- formatto-ignore -->\n```\nCode\n\n\n```".to_string()),
+        MarkdownSection::Ignored("%% formatto-ignore %%".to_string()),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn case_3() {
+    // Regions without an end continue until the end of the document.
+    // Directives in code blocks are not read.
+    let input = r#"```
+<!-- formatto-ignore-start -->
+```
+%% formatto-ignore-start %%
+Lorem Ipsum
+
+## Heading 2"#;
+
+    let expected_output = vec![
+        MarkdownSection::Code("```\n<!-- formatto-ignore-start -->\n```".to_string()),
+        MarkdownSection::Ignored(
+            "%% formatto-ignore-start %%\nLorem Ipsum\n\n## Heading 2".to_string(),
+        ),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn case_4() {
+    // A fence inside an ignored region does not open a code block.
+    let input = r#"<!-- formatto-ignore-start -->
+```
+<!-- formatto-ignore-end -->
+# Title
+text
+## Sub"#;
+
+    let expected_output = vec![
+        MarkdownSection::Ignored(
+            "<!-- formatto-ignore-start -->\n```\n<!-- formatto-ignore-end -->".to_string(),
+        ),
+        MarkdownSection::Heading(HeadingLevel::Top("# Title".to_string())),
+        MarkdownSection::Content("text".to_string()),
+        MarkdownSection::Heading(HeadingLevel::FirstSub("## Sub".to_string())),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
@@ -0,0 +1,56 @@
+use crate::{
+    testing::get_example_config,
+    tools::{
+        parsing::get_sections,
+        tokens::{HeadingLevel, MarkdownSection},
+    },
+};
+
+#[test]
+fn case_1() {
+    let input = r#"## Heading 2
+Lorem Ipsum:
+- Item
+  continued
+
+- Item
+lazy continuation
+    1. Nested item
+
+Lorem Ipsum
+2. Not a list item"#;
+
+    let expected_output = vec![
+        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
+        MarkdownSection::Content("Lorem Ipsum:".to_string()),
+        MarkdownSection::List(
+            "- Item\n  continued\n\n- Item\nlazy continuation\n    1. Nested item".to_string(),
+        ),
+        MarkdownSection::Content("Lorem Ipsum\n2. Not a list item".to_string()),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn case_2() {
+    let input = r#"1) First
+2) Second
+***
+* Item
+- Other list"#;
+
+    let expected_output = vec![
+        MarkdownSection::List("1) First\n2) Second".to_string()),
+        MarkdownSection::ThematicBreak("***".to_string()),
+        MarkdownSection::List("* Item\n- Other list".to_string()),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
@@ -0,0 +1,146 @@
+use crate::{
+    testing::get_example_config,
+    tools::{
+        parsing::get_sections,
+        tokens::{HeadingLevel, MarkdownSection},
+    },
+};
+
+#[test]
+fn case_1() {
+    let input = r#"## Heading 2
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+$$
+\begin{aligned}
+a &= b \\
+
+# c &= d
+\end{aligned}
+$$
+Lorem Ipsum"#;
+
+    let expected_output = vec![
+        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
+        MarkdownSection::Content(
+            "Lorem Ipsum is simply dummy text of the printing and typesetting industry."
+                .to_string(),
+        ),
+        MarkdownSection::Math(
+            r#"$$
+\begin{aligned}
+a &= b \\
+
+# c &= d
+\end{aligned}
+$$"#
+            .to_string(),
+        ),
+        MarkdownSection::Content("Lorem Ipsum".to_string()),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn case_2() {
+    let input = r#"## Heading 2
+$$
+x
+---
+y
+===
+$$"#;
+
+    let expected_output = vec![
+        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
+        MarkdownSection::Math("$$\nx\n---\ny\n===\n$$".to_string()),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn case_3() {
+    let input = r#"Lorem Ipsum
+$$ e^{i\pi} + 1 = 0 $$
+$$\frac{a}{b}
+= c$$"#;
+
+    let expected_output = vec![
+        MarkdownSection::Content("Lorem Ipsum".to_string()),
+        MarkdownSection::Math(r#"$$ e^{i\pi} + 1 = 0 $$"#.to_string()),
+        MarkdownSection::Math("$$\\frac{a}{b}\n= c$$".to_string()),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn case_4() {
+    // Unclosed math blocks are read as contents.
+    let input = r#"## Heading 2
+$$ is not closed.
+Lorem Ipsum"#;
+
+    let expected_output = vec![
+        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
+        MarkdownSection::Content("$$ is not closed.\nLorem Ipsum".to_string()),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn case_5() {
+    // Fences inside math blocks are a part of them.
+    let input = r#"$$
+\begin{x}
+```
+```
+$$"#;
+
+    let expected_output = vec![MarkdownSection::Math(
+        "$$\n\\begin{x}\n```\n```\n$$".to_string(),
+    )];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn case_6() {
+    // An unclosed fence inside a math block does not open a code block.
+    let input = r#"# T
+$$
+```
+x
+$$
+text
+## S"#;
+
+    let expected_output = vec![
+        MarkdownSection::Heading(HeadingLevel::Top("# T".to_string())),
+        MarkdownSection::Math("$$\n```\nx\n$$".to_string()),
+        MarkdownSection::Content("text".to_string()),
+        MarkdownSection::Heading(HeadingLevel::FirstSub("## S".to_string())),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
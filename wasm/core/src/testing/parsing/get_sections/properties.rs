@@ -0,0 +1,285 @@
+use crate::{
+    testing::get_example_config,
+    tools::{
+        parsing::get_sections,
+        tokens::{HeadingLevel, MarkdownSection},
+    },
+};
+
+/// Property section only.
+#[test]
+fn case_1() {
+    let input = r#"---
+aliases:
+- Test
+---
+"#;
+
+    let expected_output = vec![MarkdownSection::Property(
+        "---\naliases:\n- Test\n---".to_string(),
+    )];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
+
+/// Properties with other sections.
+#[test]
+fn case_2() {
+    let input = r#"---
+aliases:
+- Test
+---
+
+## Heading 2
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+
+### Heading 3
+
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+
+#### Heading 4
+## Heading 2"#;
+
+    let expected_output = vec![
+        MarkdownSection::Property("---\naliases:\n- Test\n---".to_string()),
+        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
+        MarkdownSection::Content(
+            "Lorem Ipsum is simply dummy text of the printing and typesetting industry."
+                .to_string(),
+        ),
+        MarkdownSection::Heading(HeadingLevel::FirstSub("### Heading 3".to_string())),
+        MarkdownSection::Content(
+            "Lorem Ipsum is simply dummy text of the printing and typesetting industry."
+                .to_string(),
+        ),
+        MarkdownSection::Heading(HeadingLevel::FirstSub("#### Heading 4".to_string())),
+        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
+
+/// TOML properties.
+#[test]
+fn case_3() {
+    let input = r#"+++
+title = "Lorem Ipsum"
+tags = ["a", "b"]
++++
+## Heading 2"#;
+
+    let expected_output = vec![
+        MarkdownSection::Property(
+            "+++\ntitle = \"Lorem Ipsum\"\ntags = [\"a\", \"b\"]\n+++".to_string(),
+        ),
+        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
+
+/// JSON properties.
+#[test]
+fn case_4() {
+    let input = r#";;;
+"title": "Lorem Ipsum"
+;;;
+
+{
+    "title": "Lorem Ipsum",
+    "tags": ["a", "b"]
+}"#;
+
+    let expected_output = vec![
+        MarkdownSection::Property(";;;\n\"title\": \"Lorem Ipsum\"\n;;;".to_string()),
+        MarkdownSection::Content(
+            "{\n    \"title\": \"Lorem Ipsum\",\n    \"tags\": [\"a\", \"b\"]\n}".to_string(),
+        ),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
+
+/// JSON properties without fences.
+#[test]
+fn case_5() {
+    let input = r#"{
+    "title": "{Lorem} \"Ipsum\"",
+    "tags": ["a", "b"]
+}
+
+Lorem Ipsum"#;
+
+    let expected_output = vec![
+        MarkdownSection::Property(
+            "{\n    \"title\": \"{Lorem} \\\"Ipsum\\\"\",\n    \"tags\": [\"a\", \"b\"]\n}"
+                .to_string(),
+        ),
+        MarkdownSection::Content("Lorem Ipsum".to_string()),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
+
+/// Properties of documents with CRLF line breaks.
+#[test]
+fn case_6() {
+    let input = "---\r\ntitle: Lorem Ipsum\r\n---\r\nLorem Ipsum";
+
+    let expected_output = vec![
+        MarkdownSection::Property("---\r\ntitle: Lorem Ipsum\r\n---\r".to_string()),
+        MarkdownSection::Content("Lorem Ipsum".to_string()),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
+
+/// TOML and JSON properties of documents with CRLF line breaks.
+#[test]
+fn case_7() {
+    let input = "+++\r\ntitle = \"Lorem Ipsum\"\r\n+++\r\nLorem Ipsum";
+
+    let expected_output = vec![
+        MarkdownSection::Property("+++\r\ntitle = \"Lorem Ipsum\"\r\n+++\r".to_string()),
+        MarkdownSection::Content("Lorem Ipsum".to_string()),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+
+    let input = ";;;\r\n\"title\": \"Lorem Ipsum\"\r\n;;;\r\nLorem Ipsum";
+
+    let expected_output = vec![
+        MarkdownSection::Property(";;;\r\n\"title\": \"Lorem Ipsum\"\r\n;;;\r".to_string()),
+        MarkdownSection::Content("Lorem Ipsum".to_string()),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
+
+/// Invalid property syntax.
+/// It gets read as a content section.
+#[test]
+fn invalid_input_1() {
+    let input = r#"---INVALID
+aliases:
+---
+- Test
+---INVALID
+---INVALID
+---INVALID
+
+## Heading 2
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+
+### Heading 3
+
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+
+#### Heading 4
+## Heading 2"#;
+
+    let expected_output = vec![
+        MarkdownSection::Content("---INVALID\naliases:\n---".to_string()),
+        MarkdownSection::List("- Test\n---INVALID\n---INVALID\n---INVALID".to_string()),
+        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
+        MarkdownSection::Content(
+            "Lorem Ipsum is simply dummy text of the printing and typesetting industry."
+                .to_string(),
+        ),
+        MarkdownSection::Heading(HeadingLevel::FirstSub("### Heading 3".to_string())),
+        MarkdownSection::Content(
+            "Lorem Ipsum is simply dummy text of the printing and typesetting industry."
+                .to_string(),
+        ),
+        MarkdownSection::Heading(HeadingLevel::FirstSub("#### Heading 4".to_string())),
+        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
+
+/// Invalid property syntax.
+/// It gets read as a content section.
+#[test]
+fn invalid_input_2() {
+    let input = r#"Text
+
+---
+
+## Heading 2"#;
+
+    let expected_output = vec![
+        MarkdownSection::Content("Text".to_string()),
+        MarkdownSection::ThematicBreak("---".to_string()),
+        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
+
+/// Braces at the first line that are not a JSON object.
+/// They get read as a content section.
+#[test]
+fn invalid_input_3() {
+    let input = r#"{Lorem} Ipsum
++++
+Lorem Ipsum"#;
+
+    let expected_output = vec![MarkdownSection::Content(
+        "{Lorem} Ipsum\n+++\nLorem Ipsum".to_string(),
+    )];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
+
+/// Template tags are not JSON properties.
+#[test]
+fn invalid_input_4() {
+    let input = r#"{{date}}
+## Heading 2
+Lorem Ipsum"#;
+
+    let expected_output = vec![
+        MarkdownSection::Content("{{date}}".to_string()),
+        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
+        MarkdownSection::Content("Lorem Ipsum".to_string()),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
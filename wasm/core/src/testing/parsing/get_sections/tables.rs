@@ -0,0 +1,57 @@
+use crate::{
+    testing::get_example_config,
+    tools::{
+        parsing::get_sections,
+        tokens::{HeadingLevel, MarkdownSection},
+    },
+};
+
+#[test]
+fn case_1() {
+    let input = r#"## Heading 2
+Lorem Ipsum
+
+| Name | Value \| Escaped |
+| :--- | ---------------: |
+| A    | 1                |
+B | 2
+Lorem Ipsum"#;
+
+    let expected_output = vec![
+        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
+        MarkdownSection::Content("Lorem Ipsum".to_string()),
+        MarkdownSection::Table(
+            r#"| Name | Value \| Escaped |
+| :--- | ---------------: |
+| A    | 1                |
+B | 2
+Lorem Ipsum"#
+                .to_string(),
+        ),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn case_2() {
+    // Not tables.
+    let input = r#"a | b
+| --- |
+
+Lorem Ipsum
+| a |
+| - |"#;
+
+    let expected_output = vec![MarkdownSection::Content(
+        "a | b\n| --- |\n\nLorem Ipsum\n| a |\n| - |".to_string(),
+    )];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
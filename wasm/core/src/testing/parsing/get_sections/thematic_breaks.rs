@@ -0,0 +1,105 @@
+use crate::{
+    testing::get_example_config,
+    tools::{
+        parsing::get_sections,
+        tokens::{Blockquote, HeadingLevel, MarkdownSection},
+    },
+};
+
+#[test]
+fn case_1() {
+    let input = r#"## Heading 2
+Lorem Ipsum
+* * *
+Lorem Ipsum
+
+- - -
+___
+    ***"#;
+
+    let expected_output = vec![
+        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
+        MarkdownSection::Content("Lorem Ipsum".to_string()),
+        MarkdownSection::ThematicBreak("* * *".to_string()),
+        MarkdownSection::Content("Lorem Ipsum".to_string()),
+        MarkdownSection::ThematicBreak("- - -".to_string()),
+        MarkdownSection::ThematicBreak("___".to_string()),
+        MarkdownSection::Content("    ***".to_string()),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn case_2() {
+    // `---` after other blocks cannot be a heading underline.
+    let input = r#"## Heading 2
+- Item
+---
+> Lorem Ipsum
+---
+```
+Code
+```
+---"#;
+
+    let expected_output = vec![
+        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
+        MarkdownSection::List("- Item".to_string()),
+        MarkdownSection::ThematicBreak("---".to_string()),
+        MarkdownSection::Blockquote(Blockquote {
+            text: "> Lorem Ipsum".to_string(),
+            prefix: "> ".to_string(),
+            is_callout: false,
+            sections: vec![MarkdownSection::Content("Lorem Ipsum".to_string())],
+        }),
+        MarkdownSection::ThematicBreak("---".to_string()),
+        MarkdownSection::Code("```\nCode\n```".to_string()),
+        MarkdownSection::ThematicBreak("---".to_string()),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn case_3() {
+    // `---` at the first line is not a property section without a closing line.
+    let input = r#"---
+Lorem Ipsum
+## Heading 2"#;
+
+    let expected_output = vec![
+        MarkdownSection::ThematicBreak("---".to_string()),
+        MarkdownSection::Content("Lorem Ipsum".to_string()),
+        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn case_4() {
+    // A paragraph followed by `---` is still an alternate heading.
+    let input = r#"Heading 2
+---
+Lorem Ipsum"#;
+
+    let expected_output = vec![
+        MarkdownSection::Heading(HeadingLevel::Top("Heading 2\n---".to_string())),
+        MarkdownSection::Content("Lorem Ipsum".to_string()),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_config()).unwrap(),
+        expected_output
+    );
+}
@@ -0,0 +1,309 @@
+use crate::tools::parsing::headings::get_top_heading_level;
+
+/// Hash headings only.
+#[test]
+fn case_1() {
+    let input: Vec<&str> = r#"## Heading 2
+## Heading 2
+## Heading 2"#
+        .split('\n')
+        .collect();
+
+    let expected_output = 2;
+
+    assert_eq!(
+        get_top_heading_level(&input, false).unwrap(),
+        expected_output
+    );
+}
+
+/// Hash headings and alternate headings are mixed.
+#[test]
+fn case_2() {
+    let input: Vec<&str> = r#"## Heading 2
+## Heading 2
+## Heading 2
+
+Heading 1
+===
+
+Heading 2
+---
+"#
+    .split('\n')
+    .collect();
+
+    let expected_output = 1;
+
+    assert_eq!(
+        get_top_heading_level(&input, false).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn case_3() {
+    let input: Vec<&str> = r#"````rust
+```compile_fail
+# struct MyNonSendType(std::rc::Rc<()>);
+fn is_send<T: Send>() {}
+is_send::<MyNonSendType>();
+```
+````
+
+## Heading 2
+"#
+    .split('\n')
+    .collect();
+
+    let expected_output = 2;
+
+    assert_eq!(
+        get_top_heading_level(&input, false).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn case_4() {
+    let input: Vec<&str> = r#"````md
+```language-name
+// code
+```
+````
+
+## Heading 2
+"#
+    .split('\n')
+    .collect();
+
+    let expected_output = 2;
+
+    assert_eq!(
+        get_top_heading_level(&input, false).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn case_5() {
+    let input: Vec<&str> = r#"~~~~bash
+# comment
+~~~
+# comment
+~~~~
+
+## Heading 2
+"#
+    .split('\n')
+    .collect();
+
+    let expected_output = 2;
+
+    assert_eq!(
+        get_top_heading_level(&input, false).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn case_6() {
+    let input: Vec<&str> = r#"- Install
+   ```bash
+   # comment
+   ```
+> ```
+> # comment
+> ```
+
+## Heading 2
+"#
+    .split('\n')
+    .collect();
+
+    let expected_output = 2;
+
+    assert_eq!(
+        get_top_heading_level(&input, false).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn case_7() {
+    let input: Vec<&str> = r#"```md
+``` not closed
+# Heading in a code block
+````
+
+## Heading 2
+"#
+    .split('\n')
+    .collect();
+
+    let expected_output = 2;
+
+    assert_eq!(
+        get_top_heading_level(&input, false).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn case_8() {
+    let input: Vec<&str> = r#"$$
+# a
+$$
+## Heading 2
+$$
+b
+===
+$$"#
+    .split('\n')
+    .collect();
+
+    let expected_output = 2;
+
+    assert_eq!(
+        get_top_heading_level(&input, false).unwrap(),
+        expected_output
+    );
+}
+
+/// HTML blocks start the same way as in `get_sections`.
+#[test]
+fn case_9() {
+    let input: Vec<&str> = r#"```
+code
+```
+<custom-tag>
+# H
+
+## A"#
+        .split('\n')
+        .collect();
+
+    let expected_output = 2;
+
+    assert_eq!(
+        get_top_heading_level(&input, false).unwrap(),
+        expected_output
+    );
+}
+
+/// Fences inside comments do not open code blocks.
+#[test]
+fn case_10() {
+    let input: Vec<&str> = r#"<!--
+```
+-->
+## Heading 2"#
+        .split('\n')
+        .collect();
+
+    let expected_output = 2;
+
+    assert_eq!(
+        get_top_heading_level(&input, false).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn case_11() {
+    // Lines that continue a blockquote are not heading titles.
+    let input: Vec<&str> = r#"## Heading 2
+> Lorem Ipsum
+INVALID
+==="#
+        .split('\n')
+        .collect();
+
+    let expected_output = 2;
+
+    assert_eq!(
+        get_top_heading_level(&input, false).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn case_12() {
+    // Headings in comments opened in the middle of a line are not counted.
+    let input: Vec<&str> = r#"## Heading 2
+Lorem Ipsum %%
+# Commented Heading
+%%
+%% a %% b %%
+# Commented Heading
+%%"#
+    .split('\n')
+    .collect();
+
+    let expected_output = 2;
+
+    assert_eq!(
+        get_top_heading_level(&input, false).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn case_13() {
+    // Hashes without a space after them are headings only when they are read.
+    let input: Vec<&str> = r#"### Heading 3
+##Heading 2"#
+        .split('\n')
+        .collect();
+
+    assert_eq!(get_top_heading_level(&input, true).unwrap(), 2);
+    assert_eq!(get_top_heading_level(&input, false).unwrap(), 3);
+}
+
+#[test]
+fn invalid_input_1() {
+    let input: Vec<&str> = r#"## Heading 2
+## Heading 2
+## Heading 2
+
+===
+INVALID
+
+---
+INVALID
+"#
+    .split('\n')
+    .collect();
+
+    let expected_output = 2;
+
+    assert_eq!(
+        get_top_heading_level(&input, false).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn invalid_input_2() {
+    let input: Vec<&str> = r#"## Heading 2
+## Heading 2
+## Heading 2
+
+INVALID
+====
+INVALID
+
+INVALID
+---
+INVALID
+"#
+    .split('\n')
+    .collect();
+
+    let expected_output = 1;
+
+    assert_eq!(
+        get_top_heading_level(&input, false).unwrap(),
+        expected_output
+    );
+}
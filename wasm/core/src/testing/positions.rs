@@ -0,0 +1,111 @@
+use crate::{map_positions, testing::get_example_config, Position};
+
+#[test]
+fn case_1() {
+    let input = r#"## Heading 2
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+### Heading 3
+
+
+
+Lorem Ipsum is simply dummy text of the printing and typesetting industry."#;
+
+    let positions = vec![
+        Position { line: 0, ch: 3 },
+        Position { line: 2, ch: 4 },
+        Position { line: 4, ch: 0 },
+        Position { line: 6, ch: 11 },
+    ];
+    let expected_output = vec![
+        Position { line: 0, ch: 3 },
+        Position { line: 3, ch: 4 },
+        Position { line: 4, ch: 0 },
+        Position { line: 4, ch: 11 },
+    ];
+
+    assert_eq!(
+        map_positions(input, &get_example_config(), &positions).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn case_2() {
+    let input = r#"## Heading 2
+Lorem Ipsum   
+
+
+"#;
+
+    let positions = vec![Position { line: 1, ch: 14 }, Position { line: 4, ch: 0 }];
+    let expected_output = vec![Position { line: 1, ch: 11 }, Position { line: 1, ch: 11 }];
+
+    assert_eq!(
+        map_positions(input, &get_example_config(), &positions).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn case_3() {
+    let input = r#"## 제목
+
+
+```
+코드
+```"#;
+
+    let positions = vec![Position { line: 4, ch: 2 }, Position { line: 0, ch: 100 }];
+    let expected_output = vec![Position { line: 2, ch: 2 }, Position { line: 0, ch: 5 }];
+
+    assert_eq!(
+        map_positions(input, &get_example_config(), &positions).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn case_4() {
+    // Positions in blockquotes are mapped through the sections inside them.
+    let input = r#"## Heading 2
+> [!note]
+> ## Heading
+> Lorem Ipsum
+> more"#;
+
+    let positions = vec![
+        Position { line: 2, ch: 5 },
+        Position { line: 4, ch: 3 },
+        Position { line: 1, ch: 0 },
+    ];
+    let expected_output = vec![
+        Position { line: 6, ch: 5 },
+        Position { line: 8, ch: 3 },
+        Position { line: 2, ch: 0 },
+    ];
+
+    assert_eq!(
+        map_positions(input, &get_example_config(), &positions).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn case_5() {
+    // A thematic break after a hash heading is not its underline.
+    let input = r#"# Heading 1
+---
+Lorem Ipsum
+## Heading 2
+Lorem Ipsum
+## Heading 2
+Lorem Ipsum"#;
+
+    let positions = vec![Position { line: 6, ch: 5 }];
+    let expected_output = vec![Position { line: 11, ch: 5 }];
+
+    assert_eq!(
+        map_positions(input, &get_example_config(), &positions).unwrap(),
+        expected_output
+    );
+}
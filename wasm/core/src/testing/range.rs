@@ -0,0 +1,106 @@
+use crate::{format_range, testing::get_example_config};
+
+#[test]
+fn case_1() {
+    let input = r#"# Heading 1
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+## Heading 2
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+## Heading 2
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+# Heading 1"#;
+
+    let expected_output = r#"# Heading 1
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+## Heading 2
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+
+
+## Heading 2
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+# Heading 1"#;
+
+    assert_eq!(
+        format_range(input, &get_example_config(), 4..5).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn case_2() {
+    let input = r#"# Heading 1
+
+
+
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+## Heading 2
+
+
+
+Lorem Ipsum is simply dummy text of the printing and typesetting industry."#;
+
+    // The range is widened to the whole content section and the gap before it.
+    let expected_output = r#"# Heading 1
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+## Heading 2
+
+
+
+Lorem Ipsum is simply dummy text of the printing and typesetting industry."#;
+
+    assert_eq!(
+        format_range(input, &get_example_config(), 5..6).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn case_3() {
+    let input = r#"# Heading 1
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+
+
+## Heading 2
+Lorem Ipsum is simply dummy text of the printing and typesetting industry."#;
+
+    // Lines in a gap only format the gap.
+    let expected_output = r#"# Heading 1
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+
+## Heading 2
+Lorem Ipsum is simply dummy text of the printing and typesetting industry."#;
+
+    assert_eq!(
+        format_range(input, &get_example_config(), 2..3).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn case_5() {
+    // A thematic break after a hash heading is not its underline.
+    let input = r#"# Heading 1
+---
+Lorem Ipsum
+## Heading 2
+Lorem Ipsum
+## Heading 2
+Lorem Ipsum"#;
+
+    let expected_output = r#"# Heading 1
+---
+Lorem Ipsum
+## Heading 2
+Lorem Ipsum
+
+
+## Heading 2
+Lorem Ipsum"#;
+
+    assert_eq!(
+        format_range(input, &get_example_config(), 5..6).unwrap(),
+        expected_output
+    );
+}
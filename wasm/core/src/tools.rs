@@ -0,0 +1,7 @@
+pub mod edits;
+pub mod formatting;
+pub mod overrides;
+pub mod parsing;
+pub mod positions;
+pub mod spans;
+pub mod tokens;
@@ -0,0 +1,113 @@
+use serde::Serialize;
+use std::ops::Range;
+
+use crate::tools::spans::{get_region_boundaries, split_lines};
+use crate::tools::tokens::MarkdownSection;
+
+/// Replaces whole lines of an input.
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TextEdit {
+    /// The first replaced line. (Zero-based)
+    pub start_line: usize,
+    /// The line right after the last replaced line.
+    /// It is the same as `start_line` when lines are only inserted.
+    pub end_line: usize,
+    /// The text that replaces the lines, including line breaks.
+    pub replacement: String,
+}
+
+/// Returns the edits that turn an input into its formatted output.
+///
+/// The formatter only changes gaps between sections and the end of sections,
+/// so both texts are compared region by region, using the section boundaries.
+pub fn get_text_edits(input: &str, output: &str, sections: &[MarkdownSection]) -> Vec<TextEdit> {
+    get_text_edits_in_range(input, output, sections, 0..usize::MAX)
+}
+
+/// Returns the edits that turn an input into its formatted output,
+/// only for the sections and gaps that overlap with a line range.
+///
+/// The range is widened to whole sections, and the gap before each of them.
+pub fn get_text_edits_in_range(
+    input: &str,
+    output: &str,
+    sections: &[MarkdownSection],
+    lines: Range<usize>,
+) -> Vec<TextEdit> {
+    let input_lines = split_lines(input);
+    let output_lines = split_lines(output);
+
+    let (input_boundaries, output_boundaries) =
+        get_region_boundaries(&input_lines, &output_lines, sections);
+
+    // Even regions are gaps, and odd regions are sections.
+    let mut is_selected: Vec<bool> = input_boundaries
+        .windows(2)
+        .map(|region| {
+            if region[0] == region[1] {
+                lines.start <= region[0] && region[0] < lines.end
+            } else {
+                region[0] < lines.end && lines.start < region[1]
+            }
+        })
+        .collect();
+    for region in (1..is_selected.len()).step_by(2) {
+        if is_selected[region] {
+            is_selected[region - 1] = true;
+        }
+    }
+
+    let mut edits: Vec<TextEdit> = Vec::new();
+
+    for (region, (input_region, output_region)) in input_boundaries
+        .windows(2)
+        .zip(output_boundaries.windows(2))
+        .enumerate()
+    {
+        if !is_selected[region] {
+            continue;
+        }
+
+        let original = input_lines[input_region[0]..input_region[1]].concat();
+        let replacement = output_lines[output_region[0]..output_region[1]].concat();
+
+        if original == replacement {
+            continue;
+        }
+
+        // Merge edits that touch each other.
+        if let Some(last_edit) = edits.last_mut() {
+            if last_edit.end_line == input_region[0] {
+                last_edit.end_line = input_region[1];
+                last_edit.replacement.push_str(&replacement);
+                continue;
+            }
+        }
+
+        edits.push(TextEdit {
+            start_line: input_region[0],
+            end_line: input_region[1],
+            replacement,
+        });
+    }
+
+    edits
+}
+
+/// Applies edits to an input.
+/// Edits must be sorted and must not overlap each other.
+pub fn apply_text_edits(input: &str, edits: &[TextEdit]) -> String {
+    let lines = split_lines(input);
+    let mut output = String::new();
+    let mut index = 0;
+
+    for edit in edits {
+        output.push_str(&lines[index..edit.start_line].concat());
+        output.push_str(&edit.replacement);
+        index = edit.end_line;
+    }
+    output.push_str(&lines[index..].concat());
+
+    output
+}
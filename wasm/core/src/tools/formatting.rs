@@ -0,0 +1,284 @@
+use crate::option_schema::{PluginOptions, ThematicBreakStyle};
+use crate::tools::formatting::headings::{
+    format_heading, is_formatted_as_alternate_heading, repair_heading_levels,
+};
+use crate::tools::formatting::properties::format_properties;
+use crate::tools::parsing::blockquotes::is_blockquote_line;
+use crate::tools::parsing::lists::can_interrupt_paragraph;
+use crate::tools::tokens::{Blockquote, HeadingLevel, MarkdownSection};
+use crate::{FormatConfig, FormatError};
+
+pub mod headings;
+pub mod properties;
+
+/// Kinds of sections. Gaps depend on the kind of the previous section.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SectionKind {
+    Property,
+    Heading,
+    /// Contents, comments and ignored regions.
+    Paragraph,
+    Code,
+    Math,
+    Html,
+    Blockquote,
+    Callout,
+    List,
+    Table,
+    ThematicBreak,
+}
+
+impl SectionKind {
+    fn of(section: &MarkdownSection) -> Self {
+        match section {
+            MarkdownSection::Property(_) => SectionKind::Property,
+            MarkdownSection::Heading(_) => SectionKind::Heading,
+            MarkdownSection::Content(_)
+            | MarkdownSection::Comment(_)
+            | MarkdownSection::Ignored(_) => SectionKind::Paragraph,
+            MarkdownSection::Code(_) => SectionKind::Code,
+            MarkdownSection::Math(_) => SectionKind::Math,
+            MarkdownSection::Html(_) => SectionKind::Html,
+            MarkdownSection::Blockquote(blockquote) if blockquote.is_callout => {
+                SectionKind::Callout
+            }
+            MarkdownSection::Blockquote(_) => SectionKind::Blockquote,
+            MarkdownSection::List(_) => SectionKind::List,
+            MarkdownSection::Table(_) => SectionKind::Table,
+            MarkdownSection::ThematicBreak(_) => SectionKind::ThematicBreak,
+        }
+    }
+}
+
+/// Formats a parsed document.
+pub fn get_formatted_string(
+    mut sections: Vec<MarkdownSection>,
+    config: &FormatConfig,
+) -> Result<String, FormatError> {
+    repair_heading_levels(&mut sections, &config.options.format_options);
+    let mut output = format_sections(sections, config);
+
+    if config.options.format_options.insert_newline {
+        output.push('\n');
+    }
+
+    Ok(output)
+}
+
+/// Formats sections of a document or a blockquote.
+fn format_sections(sections: Vec<MarkdownSection>, config: &FormatConfig) -> String {
+    let mut output = String::new();
+
+    // Check which type of section was last parsed.
+    let mut previous_section_kind: Option<SectionKind> = None;
+
+    for section in sections {
+        let section_kind = SectionKind::of(&section);
+        let line_break_count = if output.is_empty() {
+            0
+        } else {
+            previous_section_kind.map_or(0, |previous_section_kind| {
+                get_gap(&section, previous_section_kind, &config.options) + 1
+            })
+        };
+
+        let content = match section {
+            MarkdownSection::Property(properties) => {
+                format_properties(properties, &config.options.property_options)
+            }
+            section @ MarkdownSection::Heading(_) => {
+                format_heading(section.text(), &config.options.format_options)
+            }
+            MarkdownSection::Blockquote(blockquote) => format_blockquote(blockquote, config),
+            MarkdownSection::ThematicBreak(thematic_break) => {
+                format_thematic_break(thematic_break, config)
+            }
+            section => section.text().to_string(),
+        };
+        output.push_str(&insert_line_breaks(&content, line_break_count, 0));
+
+        previous_section_kind = Some(section_kind);
+    }
+
+    output
+}
+
+/// Returns the gap before a section.
+fn get_gap(
+    section: &MarkdownSection,
+    previous_section_kind: SectionKind,
+    options: &PluginOptions,
+) -> usize {
+    let heading_gaps = &options.heading_gaps;
+    let other_gaps = &options.other_gaps;
+    let is_right_after_heading = previous_section_kind == SectionKind::Heading;
+
+    let gap = match previous_section_kind {
+        // Some sections decide gaps after them.
+        SectionKind::Property => other_gaps.after_properties,
+        SectionKind::Callout => other_gaps.after_callouts,
+        SectionKind::ThematicBreak => other_gaps.around_horizontal_rules,
+        _ => match section {
+            MarkdownSection::Property(_) => 0,
+            MarkdownSection::Heading(HeadingLevel::Top(_)) => {
+                heading_gaps.before_top_level_headings
+            }
+            MarkdownSection::Heading(HeadingLevel::FirstSub(_)) => {
+                heading_gaps.before_first_sub_heading
+            }
+            MarkdownSection::Heading(HeadingLevel::Sub(_)) => heading_gaps.before_sub_headings,
+            MarkdownSection::Content(_)
+            | MarkdownSection::Comment(_)
+            | MarkdownSection::Ignored(_) => get_content_gap(previous_section_kind, options),
+            MarkdownSection::Code(_) if is_right_after_heading => {
+                other_gaps.before_code_blocks_after_headings
+            }
+            MarkdownSection::Code(_) => other_gaps.before_code_blocks,
+            MarkdownSection::Math(_) if is_right_after_heading => {
+                other_gaps.before_math_blocks_after_headings
+            }
+            MarkdownSection::Math(_) => other_gaps.before_math_blocks,
+            MarkdownSection::Html(_) if is_right_after_heading => {
+                other_gaps.before_html_blocks_after_headings
+            }
+            MarkdownSection::Html(_) => other_gaps.before_html_blocks,
+            MarkdownSection::Blockquote(blockquote) if blockquote.is_callout => {
+                other_gaps.before_callouts
+            }
+            MarkdownSection::Blockquote(_) => get_content_gap(previous_section_kind, options),
+            MarkdownSection::List(_) if is_right_after_heading => {
+                other_gaps.before_lists_after_headings
+            }
+            MarkdownSection::List(_) => other_gaps
+                .before_lists
+                .max(get_gap_after_blocks(previous_section_kind, options)),
+            MarkdownSection::Table(_) if is_right_after_heading => {
+                other_gaps.before_tables_after_headings
+            }
+            MarkdownSection::Table(_) => other_gaps
+                .before_tables
+                .max(get_gap_after_blocks(previous_section_kind, options)),
+            MarkdownSection::ThematicBreak(_) => other_gaps.around_horizontal_rules,
+        },
+    };
+
+    gap.max(get_minimum_gap(section, previous_section_kind, options))
+}
+
+/// Returns the gap before contents, which depends on the previous section.
+fn get_content_gap(previous_section_kind: SectionKind, options: &PluginOptions) -> usize {
+    let other_gaps = &options.other_gaps;
+
+    match previous_section_kind {
+        SectionKind::Code => other_gaps.before_contents_after_code_blocks,
+        SectionKind::Math => other_gaps.before_contents_after_math_blocks,
+        SectionKind::Html => other_gaps.before_contents_after_html_blocks,
+        SectionKind::List => other_gaps.before_contents_after_lists,
+        SectionKind::Table => other_gaps.before_contents_after_tables,
+        _ => other_gaps.before_contents,
+    }
+}
+
+/// Returns the gap before lists and tables after code blocks, math blocks and HTML blocks.
+/// Lists and tables are contents too, so the gaps before contents after those blocks apply to them.
+fn get_gap_after_blocks(previous_section_kind: SectionKind, options: &PluginOptions) -> usize {
+    match previous_section_kind {
+        SectionKind::Code | SectionKind::Math | SectionKind::Html => {
+            get_content_gap(previous_section_kind, options)
+        }
+        _ => 0,
+    }
+}
+
+/// Returns the smallest gap that keeps the meaning of a section.
+fn get_minimum_gap(
+    section: &MarkdownSection,
+    previous_section_kind: SectionKind,
+    options: &PluginOptions,
+) -> usize {
+    let is_right_after_paragraph = previous_section_kind == SectionKind::Paragraph;
+
+    let needs_gap = match section {
+        // Paragraphs right after these sections would be a part of them.
+        MarkdownSection::Content(_) | MarkdownSection::Comment(_) | MarkdownSection::Ignored(_) => {
+            matches!(
+                previous_section_kind,
+                SectionKind::Blockquote
+                    | SectionKind::Callout
+                    | SectionKind::List
+                    | SectionKind::Table
+            )
+        }
+        // Blockquotes right after blockquotes would be merged into them.
+        MarkdownSection::Blockquote(_) => matches!(
+            previous_section_kind,
+            SectionKind::Blockquote | SectionKind::Callout
+        ),
+        // These sections would be a part of the paragraph before them.
+        MarkdownSection::Table(_) => is_right_after_paragraph,
+        MarkdownSection::List(list) => {
+            let first_line = list.split('\n').next().unwrap_or_default();
+            is_right_after_paragraph && !can_interrupt_paragraph(first_line)
+        }
+        // `---` right after a paragraph is a heading underline.
+        MarkdownSection::ThematicBreak(thematic_break) => {
+            is_right_after_paragraph && thematic_break.trim().chars().all(|c| c == '-')
+        }
+        // The title of an alternate heading would be a part of the section before it.
+        MarkdownSection::Heading(_) => {
+            previous_section_kind != SectionKind::Heading
+                && is_formatted_as_alternate_heading(
+                    section.text(),
+                    options.format_options.heading_style,
+                )
+        }
+        _ => false,
+    };
+
+    if needs_gap {
+        1
+    } else {
+        0
+    }
+}
+
+/// Rewrites a thematic break with the characters of the chosen style.
+fn format_thematic_break(thematic_break: String, config: &FormatConfig) -> String {
+    match config.options.format_options.thematic_break_style {
+        ThematicBreakStyle::Preserve => thematic_break,
+        ThematicBreakStyle::Asterisks => "***".to_string(),
+        ThematicBreakStyle::Underscores => "___".to_string(),
+    }
+}
+
+/// Formats sections inside a blockquote, and puts the prefix back to each line.
+/// Blockquotes with lines without a `>` marker are kept as they are,
+/// because adding markers to those lines can change what they are read as.
+fn format_blockquote(blockquote: Blockquote, config: &FormatConfig) -> String {
+    if !blockquote.text.split('\n').all(is_blockquote_line) {
+        return blockquote.text;
+    }
+
+    let prefix = blockquote.prefix;
+    let empty_line_prefix = prefix.trim_end();
+
+    format_sections(blockquote.sections, config)
+        .split('\n')
+        .map(|line| {
+            if line.is_empty() {
+                empty_line_prefix.to_string()
+            } else {
+                format!("{}{}", prefix, line)
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Inserts line breaks before and after an input.
+pub fn insert_line_breaks(input: &str, before_count: usize, after_count: usize) -> String {
+    let line_breaks_before = "\n".repeat(before_count);
+    let line_breaks_after = "\n".repeat(after_count);
+
+    format!("{}{}{}", line_breaks_before, input, line_breaks_after)
+}
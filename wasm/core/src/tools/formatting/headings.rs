@@ -0,0 +1,262 @@
+use crate::option_schema::{FormatOptions, HeadingStyle};
+use crate::tools::parsing::headings::alternate_headings::validation::{
+    get_valid_alternate_top_heading_level::get_alternate_heading_level,
+    validate_alternate_heading_title,
+};
+use crate::tools::tokens::{HeadingLevel, MarkdownSection};
+
+/// A heading split into its level and title.
+struct HeadingParts<'a> {
+    level: usize,
+    title: &'a str,
+    /// Alternate headings are written with an underline. (ex: `Heading` and `===`)
+    is_alternate: bool,
+}
+
+/// Rewrites a heading in the chosen style, and normalizes its syntax.
+pub fn format_heading(heading: &str, options: &FormatOptions) -> String {
+    let parts = match get_heading_parts(heading) {
+        Some(parts) => parts,
+        None => return heading.to_string(),
+    };
+
+    let is_alternate = is_written_as_alternate_heading(&parts, options.heading_style);
+    if is_alternate != parts.is_alternate {
+        // Rewritten headings are always written in the normalized syntax.
+        return write_heading(&parts, is_alternate);
+    }
+
+    if is_alternate {
+        normalize_alternate_heading(heading, options)
+    } else if options.normalize_heading_syntax {
+        let indentation = if options.remove_heading_indentation {
+            ""
+        } else {
+            &heading[..heading.len() - heading.trim_start().len()]
+        };
+        format!("{}{}", indentation, write_heading(&parts, false))
+    } else if options.remove_heading_indentation {
+        heading.trim_start().to_string()
+    } else {
+        heading.to_string()
+    }
+}
+
+/// Writes a heading with hashes or with an underline.
+fn write_heading(parts: &HeadingParts, is_alternate: bool) -> String {
+    if is_alternate {
+        let underline_char = if parts.level == 1 { "=" } else { "-" };
+        let underline = underline_char.repeat(parts.title.chars().count().max(3));
+        return format!("{}\n{}", parts.title, underline);
+    }
+
+    let hashes = "#".repeat(parts.level);
+    if parts.title.is_empty() {
+        hashes
+    } else {
+        format!("{} {}", hashes, parts.title)
+    }
+}
+
+/// Trims the title of an alternate heading. The underline is kept as it is.
+fn normalize_alternate_heading(heading: &str, options: &FormatOptions) -> String {
+    let (mut title, underline) = match heading.split_once('\n') {
+        Some(lines) => lines,
+        None => return heading.to_string(),
+    };
+
+    if options.normalize_heading_syntax {
+        title = title.trim_end();
+    }
+    if options.remove_heading_indentation {
+        title = title.trim_start();
+    }
+
+    format!("{}\n{}", title, underline)
+}
+
+/// Renumbers headings of a document, so that their levels do not skip.
+/// Top level headings after the first one can also be demoted, with the headings under them.
+pub fn repair_heading_levels(sections: &mut [MarkdownSection], options: &FormatOptions) {
+    if !options.fix_skipped_heading_levels && !options.demote_extra_top_level_headings {
+        return;
+    }
+
+    let (heading_indexes, mut levels): (Vec<usize>, Vec<usize>) = sections
+        .iter()
+        .enumerate()
+        .filter_map(|(index, section)| match section {
+            MarkdownSection::Heading(_) => {
+                get_heading_parts(section.text()).map(|parts| (index, parts.level))
+            }
+            _ => None,
+        })
+        .unzip();
+    let top_heading_level = match levels.iter().min() {
+        Some(&top_heading_level) => top_heading_level,
+        None => return,
+    };
+
+    if options.fix_skipped_heading_levels {
+        levels = get_continuous_levels(&levels, top_heading_level);
+    }
+    if options.demote_extra_top_level_headings {
+        demote_extra_top_level_headings(&mut levels, top_heading_level);
+    }
+
+    // Levels decide the kinds of headings, the same way the parser does.
+    let mut current_heading_level = 0;
+    for (index, level) in heading_indexes.into_iter().zip(levels) {
+        let text = set_heading_level(sections[index].text(), level);
+        let heading_level = if level == top_heading_level {
+            HeadingLevel::Top(text)
+        } else if level > current_heading_level {
+            HeadingLevel::FirstSub(text)
+        } else {
+            HeadingLevel::Sub(text)
+        };
+
+        sections[index] = MarkdownSection::Heading(heading_level);
+        current_heading_level = level;
+    }
+}
+
+/// Returns levels where each heading is at most one level deeper than the heading that contains it.
+/// Headings that are not contained by any heading are at the top level.
+fn get_continuous_levels(levels: &[usize], top_heading_level: usize) -> Vec<usize> {
+    // Original and new levels of the headings that contain the current heading.
+    let mut parents: Vec<(usize, usize)> = Vec::new();
+
+    levels
+        .iter()
+        .map(|&level| {
+            while parents
+                .last()
+                .is_some_and(|&(parent_level, _)| parent_level >= level)
+            {
+                parents.pop();
+            }
+
+            let new_level = parents
+                .last()
+                .map_or(top_heading_level, |&(_, parent_new_level)| {
+                    parent_new_level + 1
+                });
+            parents.push((level, new_level));
+
+            new_level
+        })
+        .collect()
+}
+
+/// Demotes every heading from the second top level heading, so that only the first one is left.
+/// Hash headings cannot be deeper than level 6,
+/// so nothing is demoted if a level 6 heading would have to be.
+fn demote_extra_top_level_headings(levels: &mut [usize], top_heading_level: usize) {
+    let second_top_heading_index = levels
+        .iter()
+        .enumerate()
+        .filter(|&(_, &level)| level == top_heading_level)
+        .nth(1)
+        .map(|(index, _)| index);
+
+    if let Some(second_top_heading_index) = second_top_heading_index {
+        let demoted_levels = &mut levels[second_top_heading_index..];
+        if demoted_levels.iter().any(|&level| level >= 6) {
+            return;
+        }
+
+        for level in demoted_levels {
+            *level += 1;
+        }
+    }
+}
+
+/// Changes the level of a heading, keeping the rest of it as it is written.
+/// Alternate headings deeper than level 2 are written with hashes.
+fn set_heading_level(heading: &str, level: usize) -> String {
+    let parts = match get_heading_parts(heading) {
+        Some(parts) => parts,
+        None => return heading.to_string(),
+    };
+    if parts.level == level {
+        return heading.to_string();
+    }
+
+    if let Some((title, underline)) = heading.split_once('\n') {
+        if level > 2 {
+            return write_heading(&HeadingParts { level, ..parts }, false);
+        }
+
+        let underline_char = if level == 1 { "=" } else { "-" };
+        return format!(
+            "{}\n{}",
+            title,
+            underline_char.repeat(underline.chars().count())
+        );
+    }
+
+    let hashes_start = heading.len() - heading.trim_start().len();
+    format!(
+        "{}{}{}",
+        &heading[..hashes_start],
+        "#".repeat(level),
+        &heading[hashes_start + parts.level..]
+    )
+}
+
+/// Checks if a heading is written with an underline after it is formatted.
+pub fn is_formatted_as_alternate_heading(heading: &str, style: HeadingStyle) -> bool {
+    get_heading_parts(heading).is_some_and(|parts| is_written_as_alternate_heading(&parts, style))
+}
+
+fn is_written_as_alternate_heading(parts: &HeadingParts, style: HeadingStyle) -> bool {
+    match style {
+        HeadingStyle::Preserve => parts.is_alternate,
+        HeadingStyle::Atx => false,
+        // Only level 1 and 2 headings have underlines, and only some titles can be read with them.
+        HeadingStyle::Setext => {
+            parts.level <= 2
+                && validate_alternate_heading_title(parts.title)
+                && get_alternate_heading_level(parts.title).is_none()
+        }
+    }
+}
+
+/// Reads the level and the title of a hash heading or an alternate heading.
+fn get_heading_parts(heading: &str) -> Option<HeadingParts<'_>> {
+    if let Some((title, underline)) = heading.split_once('\n') {
+        return Some(HeadingParts {
+            level: get_alternate_heading_level(underline.trim_end())?,
+            title: title.trim(),
+            is_alternate: true,
+        });
+    }
+
+    let heading = heading.trim_start();
+    let level = heading.chars().take_while(|&char| char == '#').count();
+    if level == 0 {
+        return None;
+    }
+
+    Some(HeadingParts {
+        level,
+        title: get_hash_heading_title(&heading[level..]),
+        is_alternate: false,
+    })
+}
+
+/// Removes spaces and the optional closing hashes around the title of a hash heading.
+/// (ex: ` Heading ##` -> `Heading`)
+fn get_hash_heading_title(text: &str) -> &str {
+    let title = text.trim();
+    let without_closing_hashes = title.trim_end_matches('#');
+
+    if without_closing_hashes.is_empty() {
+        ""
+    } else if without_closing_hashes.ends_with([' ', '\t']) {
+        without_closing_hashes.trim_end()
+    } else {
+        title
+    }
+}
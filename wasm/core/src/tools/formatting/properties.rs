@@ -0,0 +1,555 @@
+use std::cmp::Ordering;
+
+use crate::option_schema::{
+    PropertyKeyOrder, PropertyListStyle, PropertyOptions, PropertyQuoteStyle,
+};
+
+/// Characters that have a meaning at the start of a plain YAML value.
+const INDICATOR_CHARS: [char; 19] = [
+    '-', '?', ':', ',', '[', ']', '{', '}', '#', '&', '*', '!', '|', '>', '\'', '"', '%', '@', '`',
+];
+
+/// Properties that Obsidian reads as lists.
+const LIST_PROPERTY_KEYS: [&str; 3] = ["tags", "aliases", "cssclasses"];
+
+/// A top level property, with the comments and blank lines above it.
+struct PropertyEntry<'a> {
+    leading_lines: Vec<&'a str>,
+    key: String,
+    /// The index right after the colon of the first line.
+    value_start: usize,
+    /// The first line has the key, and the rest of the lines are a part of the value.
+    lines: Vec<&'a str>,
+}
+
+/// Formats YAML properties.
+/// Other properties, and YAML that cannot be read line by line, are kept as they are.
+pub fn format_properties(properties: String, options: &PropertyOptions) -> String {
+    if !properties.starts_with("---\n") || !properties.ends_with("\n---") {
+        return properties;
+    }
+
+    let lines: Vec<&str> = properties.split('\n').collect();
+    let (mut entries, trailing_lines) = match get_property_entries(&lines[1..lines.len() - 1]) {
+        Some(entries) => entries,
+        None => return properties,
+    };
+
+    sort_property_entries(&mut entries, options);
+
+    let mut output_lines: Vec<String> = vec!["---".to_string()];
+    for entry in &entries {
+        output_lines.extend(filter_blank_lines(&entry.leading_lines, options));
+        output_lines.extend(format_property_entry(entry, options));
+    }
+    output_lines.extend(filter_blank_lines(&trailing_lines, options));
+    output_lines.push("---".to_string());
+
+    output_lines.join("\n")
+}
+
+/// Splits lines of YAML properties into top level entries.
+/// Comments and blank lines at the end are returned separately.
+fn get_property_entries<'a>(lines: &[&'a str]) -> Option<(Vec<PropertyEntry<'a>>, Vec<&'a str>)> {
+    let mut entries: Vec<PropertyEntry> = Vec::new();
+    // Comments and blank lines that are not a part of an entry yet.
+    let mut pending_lines: Vec<&str> = Vec::new();
+
+    for &line in lines {
+        let is_value_line = line.starts_with(' ')
+            || line.starts_with('\t')
+            || line.starts_with("- ")
+            || line == "-";
+
+        if is_value_line {
+            let entry = entries.last_mut()?;
+            entry.lines.append(&mut pending_lines);
+            entry.lines.push(line);
+        } else if line.trim().is_empty() || line.starts_with('#') {
+            pending_lines.push(line);
+        } else {
+            let (key, value_start) = get_property_key(line)?;
+            entries.push(PropertyEntry {
+                leading_lines: std::mem::take(&mut pending_lines),
+                key,
+                value_start,
+                lines: vec![line],
+            });
+        }
+    }
+
+    Some((entries, pending_lines))
+}
+
+/// Returns the key of a property line, and the index right after its colon.
+fn get_property_key(line: &str) -> Option<(String, usize)> {
+    // Quoted keys.
+    if let Some(quote) = line.chars().next().filter(|&c| c == '"' || c == '\'') {
+        let key_end = line[1..].find(quote)? + 1;
+        let rest = &line[key_end + 1..];
+        if rest != ":" && !rest.starts_with(": ") {
+            return None;
+        }
+
+        return Some((line[1..key_end].to_string(), key_end + 2));
+    }
+
+    let colon = match line.find(": ") {
+        Some(colon) => colon,
+        None => line.strip_suffix(':')?.len(),
+    };
+    let key = &line[..colon];
+    if key.starts_with(&['[', '{', '?', '&', '*', '!', '|', '>', '%', '@', '`'][..]) {
+        return None;
+    }
+
+    Some((key.to_string(), colon + 1))
+}
+
+/// Moves priority keys to the top, and sorts the others if needed.
+fn sort_property_entries(entries: &mut [PropertyEntry], options: &PropertyOptions) {
+    let priority_keys: Vec<&str> = options
+        .priority_keys
+        .split(',')
+        .map(str::trim)
+        .filter(|key| !key.is_empty())
+        .collect();
+    let get_rank = |entry: &PropertyEntry| {
+        priority_keys
+            .iter()
+            .position(|&key| key == entry.key)
+            .unwrap_or(priority_keys.len())
+    };
+
+    entries.sort_by(|a, b| {
+        get_rank(a)
+            .cmp(&get_rank(b))
+            .then_with(|| match options.key_order {
+                PropertyKeyOrder::Preserve => Ordering::Equal,
+                PropertyKeyOrder::Alphabetical => a.key.to_lowercase().cmp(&b.key.to_lowercase()),
+            })
+    });
+}
+
+fn filter_blank_lines(lines: &[&str], options: &PropertyOptions) -> Vec<String> {
+    lines
+        .iter()
+        .filter(|line| !options.remove_blank_lines || !line.trim().is_empty())
+        .map(|line| line.to_string())
+        .collect()
+}
+
+/// Formats a single property.
+fn format_property_entry(entry: &PropertyEntry, options: &PropertyOptions) -> Vec<String> {
+    let first_line = entry.lines[0];
+    let (key_part, value) = first_line.split_at(entry.value_start);
+    let value_lines = &entry.lines[1..];
+
+    // List properties.
+    if options.normalize_list_properties && LIST_PROPERTY_KEYS.contains(&entry.key.as_str()) {
+        if let Some(lines) = format_list_property(entry, options) {
+            return lines;
+        }
+    }
+
+    // Block lists.
+    if let Some(items) = get_block_list_items(value, value_lines) {
+        if options.list_style == PropertyListStyle::Flow
+            && items.iter().all(|item| is_flow_safe(item))
+        {
+            let items: Vec<String> = items
+                .iter()
+                .map(|item| format_quotes(item, options.quote_style))
+                .collect();
+            return vec![format!("{} [{}]", key_part, items.join(", "))];
+        }
+
+        let mut lines = vec![first_line.to_string()];
+        for &line in value_lines {
+            if line.trim().is_empty() {
+                if !options.remove_blank_lines {
+                    lines.push(line.to_string());
+                }
+                continue;
+            }
+            if options.quote_style == PropertyQuoteStyle::Preserve {
+                lines.push(line.to_string());
+                continue;
+            }
+
+            let marker_end = line.find('-').unwrap_or_default() + 1;
+            let (marker, item) = line.split_at(marker_end);
+            let (item, comment) = split_trailing_comment(item.trim_start());
+            lines.push(format!(
+                "{} {}{}",
+                marker,
+                format_quotes(item, options.quote_style),
+                comment
+            ));
+        }
+        return lines;
+    }
+
+    if value_lines.is_empty() {
+        let (scalar, comment) = split_trailing_comment(value.trim_start());
+
+        // Flow lists.
+        if let Some(items) = get_flow_list_items(scalar, false) {
+            let items: Vec<String> = items
+                .iter()
+                .map(|item| format_quotes(item, options.quote_style))
+                .collect();
+
+            if options.list_style == PropertyListStyle::Block
+                && !items.is_empty()
+                && comment.is_empty()
+            {
+                let mut lines = vec![key_part.to_string()];
+                lines.extend(items.iter().map(|item| format!("  - {}", item)));
+                return lines;
+            }
+            if options.quote_style == PropertyQuoteStyle::Preserve {
+                return vec![first_line.to_string()];
+            }
+            return vec![format!("{} [{}]{}", key_part, items.join(", "), comment)];
+        }
+
+        // Single line values.
+        if scalar.is_empty() || options.quote_style == PropertyQuoteStyle::Preserve {
+            return vec![first_line.to_string()];
+        }
+        return vec![format!(
+            "{} {}{}",
+            key_part,
+            format_quotes(scalar, options.quote_style),
+            comment
+        )];
+    }
+
+    // Other values, such as maps and block scalars.
+    // Blank lines in block scalars are a part of the text.
+    let is_block_scalar = value.trim_start().starts_with(&['|', '>'][..]);
+    entry
+        .lines
+        .iter()
+        .filter(|line| is_block_scalar || !options.remove_blank_lines || !line.trim().is_empty())
+        .map(|line| line.to_string())
+        .collect()
+}
+
+/// Writes a list property as a list, without `#` before tags and duplicates.
+/// Returns `None` if its value cannot be read as a list.
+fn format_list_property(entry: &PropertyEntry, options: &PropertyOptions) -> Option<Vec<String>> {
+    let first_line = entry.lines[0];
+    let (key_part, value) = first_line.split_at(entry.value_start);
+    let value_lines = &entry.lines[1..];
+    let is_tags = entry.key == "tags";
+
+    // Values can be lists, or values separated by commas. (ex: `tags: a, b`)
+    let (scalar, comment) = split_trailing_comment(value.trim_start());
+    let items: Vec<&str> = if let Some(items) = get_block_list_items(value, value_lines) {
+        items
+    } else if !value_lines.is_empty() || scalar.is_empty() || !comment.is_empty() {
+        return None;
+    } else if let Some(items) = get_flow_list_items(scalar, is_tags) {
+        items
+    } else if scalar.starts_with(['[', '{']) {
+        // Flow collections that cannot be read are kept as they are.
+        return None;
+    } else if get_quoted_text(scalar).is_some() {
+        vec![scalar]
+    } else {
+        scalar
+            .split(',')
+            .flat_map(|item| {
+                // Tags and CSS classes cannot have spaces.
+                if entry.key == "aliases" {
+                    vec![item.trim()]
+                } else {
+                    item.split_whitespace().collect()
+                }
+            })
+            .filter(|item| !item.is_empty())
+            .collect()
+    };
+
+    let mut values: Vec<(String, Option<char>)> = Vec::new();
+    for item in items {
+        let (text, quote) = match get_quoted_text(item) {
+            Some(text) => (text, item.chars().next()),
+            None if item.starts_with(&['"', '\''][..]) => return None,
+            None => (item.to_string(), None),
+        };
+
+        let mut text = if is_tags {
+            text.trim_start_matches('#').trim().to_string()
+        } else {
+            text.trim().to_string()
+        };
+        if is_tags && options.lowercase_tags {
+            text = text.to_lowercase();
+        }
+
+        // Obsidian reads tags without cases.
+        let is_duplicate = values.iter().any(|(value, _)| {
+            value == &text || (is_tags && value.to_lowercase() == text.to_lowercase())
+        });
+        if !text.is_empty() && !is_duplicate {
+            values.push((text, quote));
+        }
+    }
+    if values.is_empty() {
+        return None;
+    }
+
+    if options.sort_list_properties {
+        values.sort_by_key(|(value, _)| value.to_lowercase());
+    }
+
+    let is_flow = options.list_style == PropertyListStyle::Flow;
+    let items: Vec<String> = values
+        .iter()
+        .map(|(value, quote)| format_list_value(value, *quote, is_flow, options.quote_style))
+        .collect();
+
+    if is_flow {
+        return Some(vec![format!("{} [{}]", key_part, items.join(", "))]);
+    }
+
+    // Block lists keep their indentation.
+    let indent = value_lines
+        .iter()
+        .find(|line| !line.trim().is_empty())
+        .map_or("  ", |line| &line[..line.len() - line.trim_start().len()]);
+    let mut lines = vec![key_part.to_string()];
+    lines.extend(items.iter().map(|item| format!("{}- {}", indent, item)));
+
+    Some(lines)
+}
+
+/// Writes a value of a list property.
+/// Values that were quoted, or cannot be written without quotes, are quoted.
+fn format_list_value(
+    value: &str,
+    quote: Option<char>,
+    is_flow: bool,
+    quote_style: PropertyQuoteStyle,
+) -> String {
+    let needs_quotes = value.starts_with(&INDICATOR_CHARS[..])
+        || value.contains(": ")
+        || value.contains(" #")
+        || value.ends_with(':')
+        || (is_flow && value.contains(&[',', '[', ']', '{', '}'][..]));
+    if quote.is_none() && !needs_quotes {
+        return value.to_string();
+    }
+
+    let quote = match quote_style {
+        PropertyQuoteStyle::Preserve => quote.unwrap_or('"'),
+        PropertyQuoteStyle::Double => '"',
+        PropertyQuoteStyle::Single => '\'',
+    };
+    quote_text(value, quote)
+}
+
+/// Returns items of a block list, if every value line is a simple list item.
+fn get_block_list_items<'a>(value: &str, value_lines: &[&'a str]) -> Option<Vec<&'a str>> {
+    if !value.trim().is_empty() {
+        return None;
+    }
+
+    let mut items: Vec<&str> = Vec::new();
+    let mut marker_indent: Option<&str> = None;
+
+    for &line in value_lines {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let trimmed_line = line.trim_start();
+        let indent = &line[..line.len() - trimmed_line.len()];
+        if marker_indent.is_some_and(|marker_indent| marker_indent != indent) {
+            return None;
+        }
+        marker_indent = Some(indent);
+
+        let item = trimmed_line.strip_prefix("- ")?.trim();
+        let (scalar, comment) = split_trailing_comment(item);
+        if !comment.is_empty() || !is_simple_scalar(scalar) {
+            return None;
+        }
+        items.push(scalar);
+    }
+
+    if items.is_empty() {
+        None
+    } else {
+        Some(items)
+    }
+}
+
+/// Returns items of a flow list. (ex: `[a, "b"]`)
+/// Items can start with `#` if `allows_hashes` is true. (ex: `[#tag]`)
+fn get_flow_list_items(scalar: &str, allows_hashes: bool) -> Option<Vec<&str>> {
+    let inner = scalar.strip_prefix('[')?.strip_suffix(']')?;
+
+    let mut items: Vec<&str> = Vec::new();
+    let mut item_start = 0;
+    let mut quote: Option<char> = None;
+    let mut is_escaped = false;
+
+    for (index, char) in inner.char_indices() {
+        if let Some(opening_quote) = quote {
+            if is_escaped {
+                is_escaped = false;
+            } else if char == '\\' && opening_quote == '"' {
+                is_escaped = true;
+            } else if char == opening_quote {
+                quote = None;
+            }
+            continue;
+        }
+
+        match char {
+            '"' | '\'' if inner[item_start..index].trim().is_empty() => quote = Some(char),
+            '[' | ']' | '{' | '}' => return None,
+            ',' => {
+                items.push(inner[item_start..index].trim());
+                item_start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    if quote.is_some() {
+        return None;
+    }
+
+    // A trailing comma does not make an item.
+    let last_item = inner[item_start..].trim();
+    if !last_item.is_empty() {
+        items.push(last_item);
+    }
+
+    let is_simple_item = |item: &str| {
+        is_simple_scalar(item)
+            || (allows_hashes && item.strip_prefix('#').is_some_and(is_simple_scalar))
+    };
+    if items.iter().all(|item| is_simple_item(item)) {
+        Some(items)
+    } else {
+        None
+    }
+}
+
+/// Checks if a value is a single quoted string or a plain value that is not a map or a list.
+fn is_simple_scalar(scalar: &str) -> bool {
+    if scalar.is_empty() {
+        return false;
+    }
+    if get_quoted_text(scalar).is_some() {
+        return true;
+    }
+
+    !scalar.starts_with(&['-', '[', '{', '|', '>', '#', '"', '\''][..])
+        && !scalar.contains(": ")
+        && !scalar.ends_with(':')
+}
+
+/// Checks if a list item can be written inside brackets.
+fn is_flow_safe(item: &str) -> bool {
+    get_quoted_text(item).is_some() || !item.contains(&[',', '[', ']', '{', '}'][..])
+}
+
+/// Splits a value and the comment after it.
+/// The comment keeps the whitespace before it.
+pub fn split_trailing_comment(value: &str) -> (&str, &str) {
+    let mut quote: Option<char> = None;
+    let mut is_escaped = false;
+    let mut previous_char = ' ';
+
+    for (index, char) in value.char_indices() {
+        if let Some(opening_quote) = quote {
+            if is_escaped {
+                is_escaped = false;
+            } else if char == '\\' && opening_quote == '"' {
+                is_escaped = true;
+            } else if char == opening_quote {
+                quote = None;
+            }
+        } else if char == '#' && previous_char.is_whitespace() {
+            let scalar = value[..index].trim_end();
+            return (scalar, &value[scalar.len()..]);
+        } else if (char == '"' || char == '\'')
+            && (previous_char.is_whitespace() || previous_char == '[' || previous_char == ',')
+        {
+            quote = Some(char);
+        }
+        previous_char = char;
+    }
+
+    let scalar = value.trim_end();
+    (scalar, &value[scalar.len()..])
+}
+
+/// Rewrites a quoted value with the quotes of the chosen style.
+/// Plain values, and values that cannot be written with the other quotes, are kept.
+fn format_quotes(scalar: &str, quote_style: PropertyQuoteStyle) -> String {
+    let is_double_quoted = scalar.starts_with('"');
+    let text = match get_quoted_text(scalar) {
+        Some(text) => text,
+        None => return scalar.to_string(),
+    };
+
+    match quote_style {
+        PropertyQuoteStyle::Double if !is_double_quoted => quote_text(&text, '"'),
+        PropertyQuoteStyle::Single if is_double_quoted => quote_text(&text, '\''),
+        _ => scalar.to_string(),
+    }
+}
+
+/// Writes a text with quotes, escaping characters if needed.
+fn quote_text(text: &str, quote: char) -> String {
+    if quote == '\'' {
+        format!("'{}'", text.replace('\'', "''"))
+    } else {
+        format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+}
+
+/// Returns the text of a quoted value.
+/// Double quoted values with escape sequences other than `\"` and `\\` are not read,
+/// because they cannot be written with single quotes.
+pub fn get_quoted_text(scalar: &str) -> Option<String> {
+    if scalar.len() < 2 {
+        return None;
+    }
+
+    let quote = scalar.chars().next()?;
+    let inner = match quote {
+        '\'' | '"' => scalar[1..].strip_suffix(quote)?,
+        _ => return None,
+    };
+
+    if quote == '\'' {
+        // `''` is an escaped single quote.
+        if inner.replace("''", "").contains('\'') {
+            return None;
+        }
+        return Some(inner.replace("''", "'"));
+    }
+
+    let mut text = String::new();
+    let mut chars = inner.chars();
+    while let Some(char) = chars.next() {
+        match char {
+            '\\' => match chars.next() {
+                Some(escaped_char @ ('"' | '\\')) => text.push(escaped_char),
+                _ => return None,
+            },
+            '"' => return None,
+            _ => text.push(char),
+        }
+    }
+
+    Some(text)
+}
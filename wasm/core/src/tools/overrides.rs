@@ -0,0 +1,187 @@
+use serde_json::{Number, Value};
+
+use crate::tools::formatting::properties::{get_quoted_text, split_trailing_comment};
+use crate::tools::parsing::properties::get_properties_end;
+use crate::{FormatConfig, FormatError, PluginOptions};
+
+/// The property that holds options of a single note.
+const OVERRIDE_KEY: &str = "formatto:";
+
+/// Options written in the properties of a note.
+enum NoteOptions<'a> {
+    /// `formatto: off`
+    Off,
+    /// Option names and their values as written. (ex: `beforeTopLevelHeadings: 1`)
+    Overrides(Vec<(&'a str, &'a str)>),
+}
+
+/// Returns the config used for a document.
+/// Options in the `formatto` property of the document are applied over the given options.
+/// Returns `None` if formatting is turned off for the document.
+pub fn get_document_config(
+    input: &str,
+    config: &FormatConfig,
+) -> Result<Option<FormatConfig>, FormatError> {
+    let input_lines: Vec<&str> = input.trim().split('\n').collect();
+
+    let overrides = match get_note_options(&input_lines)? {
+        Some(NoteOptions::Off) => return Ok(None),
+        Some(NoteOptions::Overrides(overrides)) if !overrides.is_empty() => overrides,
+        _ => return Ok(Some(FormatConfig::new(config.options.clone()))),
+    };
+
+    let mut options = serde_json::to_value(&config.options).unwrap_or_default();
+    for &(name, value) in &overrides {
+        // Option names are unique across option groups.
+        let option = options
+            .as_object_mut()
+            .and_then(|groups| {
+                groups
+                    .values_mut()
+                    .find_map(|group| group.as_object_mut()?.get_mut(name))
+            })
+            .ok_or_else(|| FormatError::InvalidOption {
+                field: format!("formatto.{}", name),
+                value: value.to_string(),
+            })?;
+        *option = read_value(value);
+    }
+
+    // Invalid values are reported with the names written in the note.
+    let options = PluginOptions::from_value(&options).map_err(|error| match error {
+        FormatError::InvalidOption { field, value } => {
+            match overrides
+                .iter()
+                .find(|(name, _)| field.rsplit('.').next() == Some(*name))
+            {
+                Some((name, written_value)) => FormatError::InvalidOption {
+                    field: format!("formatto.{}", name),
+                    value: written_value.to_string(),
+                },
+                None => FormatError::InvalidOption { field, value },
+            }
+        }
+        error => error,
+    })?;
+
+    Ok(Some(FormatConfig::new(options)))
+}
+
+/// Reads the `formatto` property of YAML properties.
+fn get_note_options<'a>(input_lines: &[&'a str]) -> Result<Option<NoteOptions<'a>>, FormatError> {
+    if input_lines.first().map(|line| line.trim_end()) != Some("---") {
+        return Ok(None);
+    }
+    let properties_end = match get_properties_end(input_lines) {
+        Some(properties_end) => properties_end,
+        None => return Ok(None),
+    };
+    let property_lines = &input_lines[1..properties_end];
+
+    let index = match property_lines
+        .iter()
+        .position(|line| line.starts_with(OVERRIDE_KEY))
+    {
+        Some(index) => index,
+        None => return Ok(None),
+    };
+    let (value, _) = split_trailing_comment(property_lines[index][OVERRIDE_KEY.len()..].trim());
+
+    // Block maps.
+    if value.is_empty() {
+        let overrides = property_lines[index + 1..]
+            .iter()
+            .take_while(|line| {
+                line.starts_with(' ') || line.starts_with('\t') || line.trim().is_empty()
+            })
+            .map(|line| split_trailing_comment(line.trim()).0)
+            .filter(|line| !line.is_empty())
+            .map(|line| read_option(line).ok_or_else(|| get_invalid_error(line)))
+            .collect::<Result<Vec<_>, FormatError>>()?;
+
+        return Ok(Some(NoteOptions::Overrides(overrides)));
+    }
+
+    match value.to_lowercase().as_str() {
+        "off" | "false" => return Ok(Some(NoteOptions::Off)),
+        "on" | "true" => return Ok(Some(NoteOptions::Overrides(Vec::new()))),
+        _ => {}
+    }
+
+    // Flow maps.
+    let inner = value
+        .strip_prefix('{')
+        .and_then(|value| value.strip_suffix('}'))
+        .ok_or_else(|| get_invalid_error(value))?;
+    let overrides = split_flow_map(inner)
+        .into_iter()
+        .filter(|item| !item.is_empty())
+        .map(|item| read_option(item).ok_or_else(|| get_invalid_error(value)))
+        .collect::<Result<Vec<_>, FormatError>>()?;
+
+    Ok(Some(NoteOptions::Overrides(overrides)))
+}
+
+/// Splits items of a flow map by commas that are not quoted.
+fn split_flow_map(inner: &str) -> Vec<&str> {
+    let mut items: Vec<&str> = Vec::new();
+    let mut item_start = 0;
+    let mut quote: Option<char> = None;
+
+    for (index, char) in inner.char_indices() {
+        match quote {
+            Some(opening_quote) if char == opening_quote => quote = None,
+            Some(_) => {}
+            None if char == '"' || char == '\'' => quote = Some(char),
+            None if char == ',' => {
+                items.push(inner[item_start..index].trim());
+                item_start = index + 1;
+            }
+            None => {}
+        }
+    }
+    items.push(inner[item_start..].trim());
+
+    items
+}
+
+/// Reads an option name and its value. (ex: `insertNewline: false`)
+fn read_option(item: &str) -> Option<(&str, &str)> {
+    let (name, value) = item.split_once(':')?;
+    let (name, value) = (name.trim(), value.trim());
+    if name.is_empty() || value.is_empty() {
+        return None;
+    }
+
+    Some((name, value))
+}
+
+/// Reads a YAML value as a JSON value, so that it can be read like other options.
+fn read_value(value: &str) -> Value {
+    if let Some(text) = get_quoted_text(value) {
+        return Value::String(text);
+    }
+
+    match value {
+        "true" => Value::Bool(true),
+        "false" => Value::Bool(false),
+        _ => {
+            if let Ok(number) = value.parse::<u64>() {
+                Value::Number(number.into())
+            } else if let Ok(number) = value.parse::<i64>() {
+                Value::Number(number.into())
+            } else if let Some(number) = value.parse::<f64>().ok().and_then(Number::from_f64) {
+                Value::Number(number)
+            } else {
+                Value::String(value.to_string())
+            }
+        }
+    }
+}
+
+fn get_invalid_error(value: &str) -> FormatError {
+    FormatError::InvalidOption {
+        field: "formatto".to_string(),
+        value: value.to_string(),
+    }
+}
@@ -0,0 +1,591 @@
+use crate::{
+    error::SourceSpan,
+    tools::positions::Position,
+    tools::tokens::{Blockquote, HeadingLevel, MarkdownSection},
+    FormatConfig, FormatError,
+};
+
+pub mod blockquotes;
+pub mod code_blocks;
+pub mod comments;
+mod contents;
+pub mod headings;
+pub mod html_blocks;
+pub mod ignores;
+pub mod lists;
+pub mod math_blocks;
+pub mod properties;
+pub mod tables;
+pub mod thematic_breaks;
+
+#[derive(Debug)]
+struct ErrorInformation {
+    /// The number of blank lines trimmed from the start of the document.
+    leading_line_count: usize,
+    reading_section_starting_line: usize,
+    reading_section_starting_column: usize,
+}
+
+/// Serializes input into sections.
+pub fn get_sections(
+    input: &str,
+    config: &FormatConfig,
+) -> Result<Vec<MarkdownSection>, FormatError> {
+    // Hashes without a space are headings only when they are going to be normalized.
+    read_sections(
+        input,
+        true,
+        config.options.format_options.normalize_heading_syntax,
+    )
+}
+
+/// Serializes input into sections.
+/// Only documents can have properties, so `is_document` is false for the contents of blockquotes.
+fn read_sections(
+    input: &str,
+    is_document: bool,
+    reads_unspaced_hash_headings: bool,
+) -> Result<Vec<MarkdownSection>, FormatError> {
+    use super::parsing::blockquotes::{
+        get_blockquote_end, get_blockquote_prefix, is_callout, strip_blockquote_prefix,
+    };
+    use super::parsing::code_blocks::{get_opening_fence, is_closing_fence, CodeFence};
+    use super::parsing::comments::{get_opening_comment, get_unclosed_comment, CommentKind};
+    use super::parsing::contents::{append_a_line_break, finish_current_content_section};
+    use super::parsing::headings::{
+        alternate_headings::get_valid_alternate_heading_level,
+        alternate_headings::validation::validate_alternate_heading_title,
+        get_top_heading_level,
+        hash_headings::{get_hash_heading_level, validation::validate_any_hash_heading_line},
+    };
+    use super::parsing::html_blocks::get_html_block_end;
+    use super::parsing::ignores::get_ignored_region_end;
+    use super::parsing::math_blocks::get_math_block_end;
+    use super::parsing::properties::get_properties_end;
+
+    if input.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut sections: Vec<MarkdownSection> = Vec::new();
+    let input_lines: Vec<&str> = input.trim().split('\n').collect();
+
+    let document_top_heading_level =
+        get_top_heading_level(&input_lines, reads_unspaced_hash_headings);
+
+    let mut current_heading_level = 0;
+
+    // Property sections.
+    let mut temp_properties = String::new();
+    let mut reading_properties_end: Option<usize> = None;
+    let properties_end = if is_document {
+        get_properties_end(&input_lines)
+    } else {
+        None
+    };
+
+    // Code block sections.
+    let mut temp_code_block = String::new();
+    let mut is_reading_code_block = false;
+    let mut current_code_block_fence: Option<CodeFence> = None;
+
+    // Comment sections.
+    let mut temp_comment = String::new();
+    let mut current_comment_kind: Option<CommentKind> = None;
+
+    // Math block sections.
+    let mut temp_math_block = String::new();
+    let mut reading_math_block_end: Option<usize> = None;
+
+    // HTML block sections.
+    let mut temp_html_block = String::new();
+    let mut reading_html_block_end: Option<usize> = None;
+
+    // Ignored regions.
+    let mut temp_ignored_region = String::new();
+    let mut reading_ignored_region_end: Option<usize> = None;
+
+    // Blockquote sections.
+    let mut temp_blockquote = String::new();
+    let mut reading_blockquote_end: Option<usize> = None;
+
+    // Content section.
+    // Everything goes into `MarkdownSection::Content` type,
+    // unless it detects some specific Markdown syntax that needs to be parsed.
+    let mut temp_content_section = String::new();
+    let mut is_reading_content_section = false;
+    // Comments opened in the middle of a content line are a part of the content.
+    let mut content_comment_kind: Option<CommentKind> = None;
+
+    let mut error_information = ErrorInformation {
+        leading_line_count: input[..input.len() - input.trim_start().len()]
+            .matches('\n')
+            .count(),
+        reading_section_starting_line: 0,
+        reading_section_starting_column: 0,
+    };
+
+    // Iterate over lines of a document.
+    for (index, &line) in input_lines.iter().enumerate() {
+        // "is_reading_content_section" should be updated in previous iterations.
+        if line.is_empty()
+            && !is_reading_content_section
+            && !is_reading_code_block
+            && current_comment_kind.is_none()
+            && reading_math_block_end.is_none()
+            && reading_html_block_end.is_none()
+            && reading_properties_end.is_none()
+            && reading_ignored_region_end.is_none()
+        {
+            continue;
+        }
+        is_reading_content_section = true;
+
+        // The title of an alternate heading has to be the last line of the current content.
+        let alternate_heading_level: Option<usize> =
+            get_valid_alternate_heading_level(&input_lines, index).filter(|_| {
+                index > 0
+                    && temp_content_section.rsplit('\n').next() == Some(input_lines[index - 1])
+                    && validate_alternate_heading_title(input_lines[index - 1])
+            });
+
+        // Read properties.
+        if index == 0 {
+            reading_properties_end = properties_end;
+        }
+        if let Some(properties_end) = reading_properties_end {
+            append_a_line_break(&mut temp_properties, line);
+
+            if index == properties_end {
+                // Exit a property section.
+                reading_properties_end = None;
+                sections.push(MarkdownSection::Property(temp_properties.clone()));
+            }
+            continue;
+        }
+
+        // Read comments that are opened in the middle of contents.
+        if let Some(comment_kind) = content_comment_kind {
+            content_comment_kind = get_unclosed_comment(line, Some(comment_kind));
+            append_a_line_break(&mut temp_content_section, line);
+
+            if index == input_lines.len() - 1 {
+                finish_current_content_section(
+                    &mut is_reading_content_section,
+                    &mut sections,
+                    &mut temp_content_section,
+                );
+            }
+            continue;
+        }
+
+        // Read ignored regions.
+        if reading_ignored_region_end.is_none()
+            && !is_reading_code_block
+            && current_comment_kind.is_none()
+            && reading_math_block_end.is_none()
+            && reading_html_block_end.is_none()
+            && reading_blockquote_end.is_none()
+        {
+            reading_ignored_region_end = get_ignored_region_end(&input_lines, index);
+        }
+        if let Some(ignored_region_end) = reading_ignored_region_end {
+            finish_current_content_section(
+                &mut is_reading_content_section,
+                &mut sections,
+                &mut temp_content_section,
+            );
+
+            append_a_line_break(&mut temp_ignored_region, line);
+            if index == ignored_region_end {
+                // Exit an ignored region.
+                sections.push(MarkdownSection::Ignored(temp_ignored_region.clone()));
+
+                temp_ignored_region.clear();
+                reading_ignored_region_end = None;
+            }
+            continue;
+        }
+
+        // Read comments.
+        let is_opening_comment_line = current_comment_kind.is_none();
+        if is_opening_comment_line
+            && !is_reading_code_block
+            && reading_math_block_end.is_none()
+            && reading_html_block_end.is_none()
+            && reading_blockquote_end.is_none()
+        {
+            current_comment_kind = get_opening_comment(line);
+            if current_comment_kind.is_some() {
+                // Enter a comment.
+                error_information.reading_section_starting_line = index;
+                error_information.reading_section_starting_column =
+                    line.len() - line.trim_start().len();
+            }
+        }
+        if let Some(comment_kind) = current_comment_kind {
+            finish_current_content_section(
+                &mut is_reading_content_section,
+                &mut sections,
+                &mut temp_content_section,
+            );
+
+            append_a_line_break(&mut temp_comment, line);
+            let open_comment = if is_opening_comment_line {
+                None
+            } else {
+                Some(comment_kind)
+            };
+            current_comment_kind = get_unclosed_comment(line, open_comment);
+            if current_comment_kind.is_none() {
+                // Exit a comment.
+                sections.push(MarkdownSection::Comment(temp_comment.clone()));
+
+                temp_comment.clear();
+            }
+            continue;
+        }
+
+        // Read blockquotes.
+        if reading_blockquote_end.is_none()
+            && !is_reading_code_block
+            && reading_math_block_end.is_none()
+            && reading_html_block_end.is_none()
+        {
+            reading_blockquote_end = get_blockquote_end(&input_lines, index);
+            if reading_blockquote_end.is_some() {
+                error_information.reading_section_starting_line = index;
+            }
+        }
+        if let Some(blockquote_end) = reading_blockquote_end {
+            finish_current_content_section(
+                &mut is_reading_content_section,
+                &mut sections,
+                &mut temp_content_section,
+            );
+
+            append_a_line_break(&mut temp_blockquote, line);
+            if index == blockquote_end {
+                // Exit a blockquote, and read sections inside it.
+                let quoted_lines: Vec<&str> = temp_blockquote.split('\n').collect();
+                // Formatting removes empty lines at the start, so the first line with text decides the prefix.
+                let first_line = quoted_lines
+                    .iter()
+                    .copied()
+                    .find(|line| !strip_blockquote_prefix(line).trim().is_empty())
+                    .unwrap_or(quoted_lines[0]);
+                let prefix = get_blockquote_prefix(first_line);
+                let quoted_text = quoted_lines
+                    .iter()
+                    .map(|line| strip_blockquote_prefix(line))
+                    .collect::<Vec<&str>>()
+                    .join("\n");
+
+                let quoted_sections =
+                    read_sections(&quoted_text, false, reads_unspaced_hash_headings).map_err(
+                        |error| {
+                            move_error(
+                                error,
+                                error_information.leading_line_count
+                                    + error_information.reading_section_starting_line,
+                                prefix.encode_utf16().count(),
+                            )
+                        },
+                    )?;
+
+                sections.push(MarkdownSection::Blockquote(Blockquote {
+                    text: temp_blockquote.clone(),
+                    prefix: prefix.to_string(),
+                    is_callout: is_callout(strip_blockquote_prefix(first_line)),
+                    sections: quoted_sections,
+                }));
+
+                temp_blockquote.clear();
+                reading_blockquote_end = None;
+            }
+            continue;
+        }
+
+        // Read code blocks.
+        // Fences inside math blocks and HTML blocks are a part of them.
+        let opening_fence = if reading_math_block_end.is_none() && reading_html_block_end.is_none()
+        {
+            get_opening_fence(&input_lines, index)
+        } else {
+            None
+        };
+        let valid_code_block_syntax_line = match &current_code_block_fence {
+            Some(current_code_block_fence) => is_closing_fence(line, current_code_block_fence),
+            None => opening_fence.is_some(),
+        };
+        // Code blocks in list items are read as a part of the list.
+        let is_in_list_item = current_code_block_fence
+            .or(opening_fence)
+            .is_some_and(|fence| fence.is_in_list_item);
+        if is_in_list_item && (valid_code_block_syntax_line || is_reading_code_block) {
+            if !is_reading_code_block {
+                // Enter a code block.
+                error_information.reading_section_starting_line = index;
+                error_information.reading_section_starting_column =
+                    opening_fence.map_or(0, |fence| fence.indent);
+                is_reading_code_block = true;
+                current_code_block_fence = opening_fence;
+            } else if valid_code_block_syntax_line {
+                // Exit a code block.
+                is_reading_code_block = false;
+                current_code_block_fence = None;
+            }
+            append_a_line_break(&mut temp_content_section, line);
+
+            if index == input_lines.len() - 1 {
+                finish_current_content_section(
+                    &mut is_reading_content_section,
+                    &mut sections,
+                    &mut temp_content_section,
+                );
+            }
+            continue;
+        }
+        if valid_code_block_syntax_line || is_reading_code_block {
+            finish_current_content_section(
+                &mut is_reading_content_section,
+                &mut sections,
+                &mut temp_content_section,
+            );
+
+            if valid_code_block_syntax_line {
+                if !is_reading_code_block {
+                    // Enter a code block.
+                    error_information.reading_section_starting_line = index;
+                    error_information.reading_section_starting_column =
+                        opening_fence.map_or(0, |fence| fence.indent);
+                    temp_code_block.push_str(line);
+                    is_reading_code_block = true;
+                    current_code_block_fence = opening_fence;
+                    continue;
+                } else {
+                    // Exit a code block.
+                    temp_code_block.push_str(format!("\n{}", line).as_str());
+                    sections.push(MarkdownSection::Code(temp_code_block.clone()));
+                    current_code_block_fence = None;
+
+                    // Clear temporary code block.
+                    temp_code_block.clear();
+                    is_reading_code_block = false;
+                    continue;
+                }
+            }
+
+            // Keep reading the code block.
+            if is_reading_code_block {
+                if !temp_code_block.is_empty() {
+                    temp_code_block.push('\n');
+                }
+                temp_code_block.push_str(line);
+                continue;
+            }
+        }
+
+        // Read math blocks.
+        if reading_math_block_end.is_none() {
+            reading_math_block_end = get_math_block_end(&input_lines, index);
+        }
+        if let Some(math_block_end) = reading_math_block_end {
+            finish_current_content_section(
+                &mut is_reading_content_section,
+                &mut sections,
+                &mut temp_content_section,
+            );
+
+            append_a_line_break(&mut temp_math_block, line);
+            if index == math_block_end {
+                // Exit a math block.
+                sections.push(MarkdownSection::Math(temp_math_block.clone()));
+
+                temp_math_block.clear();
+                reading_math_block_end = None;
+            }
+            continue;
+        }
+
+        // Read HTML blocks.
+        if reading_html_block_end.is_none() {
+            let last_content_line = temp_content_section.rsplit('\n').next();
+            reading_html_block_end = get_html_block_end(&input_lines, index, last_content_line);
+        }
+        if let Some(html_block_end) = reading_html_block_end {
+            finish_current_content_section(
+                &mut is_reading_content_section,
+                &mut sections,
+                &mut temp_content_section,
+            );
+
+            append_a_line_break(&mut temp_html_block, line);
+            if index == html_block_end {
+                // Exit an HTML block.
+                sections.push(MarkdownSection::Html(temp_html_block.clone()));
+
+                temp_html_block.clear();
+                reading_html_block_end = None;
+            }
+            continue;
+        }
+
+        // Read hash headings.
+        if validate_any_hash_heading_line(&input_lines, index, reads_unspaced_hash_headings) {
+            if let Some(document_top_heading_level) = document_top_heading_level {
+                finish_current_content_section(
+                    &mut is_reading_content_section,
+                    &mut sections,
+                    &mut temp_content_section,
+                );
+
+                let heading_level = get_hash_heading_level(line);
+                if heading_level == document_top_heading_level {
+                    sections.push(MarkdownSection::Heading(HeadingLevel::Top(
+                        line.to_string(),
+                    )));
+                } else if heading_level > current_heading_level {
+                    sections.push(MarkdownSection::Heading(HeadingLevel::FirstSub(
+                        line.to_string(),
+                    )));
+                } else {
+                    sections.push(MarkdownSection::Heading(HeadingLevel::Sub(
+                        line.to_string(),
+                    )));
+                }
+
+                current_heading_level = heading_level;
+                continue;
+            }
+        }
+
+        // Read alternate headings.
+        if let (Some(alternate_heading_level), Some(document_top_heading_level)) =
+            (alternate_heading_level, document_top_heading_level)
+        {
+            let is_top_level = alternate_heading_level == document_top_heading_level;
+            let is_sub_level = alternate_heading_level > document_top_heading_level;
+
+            if !is_reading_code_block && (is_top_level || is_sub_level) {
+                // Take the title out of the current content.
+                let title = input_lines[index - 1];
+                let title_start = temp_content_section.len() - title.len();
+                temp_content_section.truncate(title_start.saturating_sub(1));
+
+                finish_current_content_section(
+                    &mut is_reading_content_section,
+                    &mut sections,
+                    &mut temp_content_section,
+                );
+
+                let mut section_string = title.to_string();
+                section_string.push('\n');
+                section_string.push_str(line);
+
+                if is_top_level {
+                    sections.push(MarkdownSection::Heading(HeadingLevel::Top(section_string)));
+                    current_heading_level = document_top_heading_level;
+                } else {
+                    if alternate_heading_level > current_heading_level {
+                        sections.push(MarkdownSection::Heading(HeadingLevel::FirstSub(
+                            section_string,
+                        )));
+                    } else {
+                        sections.push(MarkdownSection::Heading(HeadingLevel::Sub(section_string)));
+                    }
+                    current_heading_level = alternate_heading_level;
+                }
+
+                continue;
+            }
+        }
+
+        // Read contents.
+        if is_reading_content_section {
+            error_information.reading_section_starting_line = index;
+
+            check_parsing_error(
+                is_reading_code_block,
+                current_comment_kind.is_some(),
+                &input_lines,
+                &error_information,
+            )?;
+            append_a_line_break(&mut temp_content_section, line);
+            content_comment_kind = get_unclosed_comment(line, None);
+        }
+
+        // Run this when it's the last line.
+        if index == &input_lines.len() - 1 {
+            finish_current_content_section(
+                &mut is_reading_content_section,
+                &mut sections,
+                &mut temp_content_section,
+            );
+        }
+    }
+
+    check_parsing_error(
+        is_reading_code_block,
+        current_comment_kind.is_some(),
+        &input_lines,
+        &error_information,
+    )?;
+
+    Ok(sections)
+}
+
+/// Moves the span of an error found inside a blockquote, so that it points to the document.
+fn move_error(error: FormatError, line_offset: usize, ch_offset: usize) -> FormatError {
+    let move_span = |span: SourceSpan| SourceSpan {
+        start: Position {
+            line: span.start.line + line_offset,
+            ch: span.start.ch + ch_offset,
+        },
+        end: Position {
+            line: span.end.line + line_offset,
+            ch: span.end.ch + ch_offset,
+        },
+    };
+
+    match error {
+        FormatError::UnclosedCodeBlock { span } => FormatError::UnclosedCodeBlock {
+            span: move_span(span),
+        },
+        FormatError::UnclosedComment { span } => FormatError::UnclosedComment {
+            span: move_span(span),
+        },
+        FormatError::InvalidOption { .. } => error,
+    }
+}
+
+/// Returns an error if the document is invalid.
+fn check_parsing_error(
+    is_reading_code_block: bool,
+    is_reading_comment: bool,
+    input_lines: &[&str],
+    error_information: &ErrorInformation,
+) -> Result<(), FormatError> {
+    if !is_reading_code_block && !is_reading_comment {
+        return Ok(());
+    }
+
+    let last_line = input_lines.len() - 1;
+    let span = SourceSpan {
+        start: Position {
+            line: error_information.leading_line_count
+                + error_information.reading_section_starting_line,
+            ch: error_information.reading_section_starting_column,
+        },
+        end: Position {
+            line: error_information.leading_line_count + last_line,
+            ch: input_lines[last_line].encode_utf16().count(),
+        },
+    };
+
+    if is_reading_code_block {
+        Err(FormatError::UnclosedCodeBlock { span })
+    } else {
+        Err(FormatError::UnclosedComment { span })
+    }
+}
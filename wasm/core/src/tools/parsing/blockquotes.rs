@@ -0,0 +1,126 @@
+/// Checks if a line is a part of a blockquote. (ex: `> Quote`)
+pub fn is_blockquote_line(line: &str) -> bool {
+    let indent = line.len() - line.trim_start_matches(' ').len();
+
+    indent <= 3 && line[indent..].starts_with('>')
+}
+
+/// Returns the index of the last line of a blockquote starting at `index`.
+/// Returns `None` if the line does not start a blockquote.
+///
+/// Blockquotes end at the first line without a `>` marker,
+/// unless the line continues a paragraph in the blockquote. (ex: `> Lorem` and `Ipsum`)
+pub fn get_blockquote_end(input_lines: &[&str], index: usize) -> Option<usize> {
+    use super::code_blocks::{get_opening_fence, is_closing_fence, CodeFence};
+
+    if !is_blockquote_line(input_lines[index]) {
+        return None;
+    }
+
+    let mut end = index;
+    let mut reading_code_block_fence: Option<CodeFence> = None;
+    let mut is_in_paragraph = false;
+
+    for (line_index, &line) in input_lines.iter().enumerate().skip(index) {
+        if is_blockquote_line(line) {
+            if let Some(fence) = reading_code_block_fence {
+                if is_closing_fence(line, &fence) {
+                    reading_code_block_fence = None;
+                }
+                is_in_paragraph = false;
+            } else if let Some(fence) = get_opening_fence(input_lines, line_index) {
+                reading_code_block_fence = Some(fence);
+                is_in_paragraph = false;
+            } else {
+                is_in_paragraph = is_paragraph_line(strip_blockquote_markers(line));
+            }
+        } else if !is_in_paragraph || !is_lazy_continuation_line(input_lines, line_index) {
+            break;
+        }
+
+        end = line_index;
+    }
+
+    Some(end)
+}
+
+/// Checks if the text of a line can be continued by a line without a `>` marker.
+fn is_paragraph_line(text: &str) -> bool {
+    use super::headings::alternate_headings::validation::get_valid_alternate_top_heading_level::get_alternate_heading_level;
+    use super::headings::hash_headings::validation::validate_hash_heading;
+    use super::html_blocks::get_html_block_end;
+    use super::thematic_breaks::is_thematic_break;
+
+    !text.trim().is_empty()
+        && !validate_hash_heading(text)
+        && !is_thematic_break(text)
+        && get_alternate_heading_level(text.trim()).is_none()
+        && get_html_block_end(&[text], 0, None).is_none()
+}
+
+/// Checks if a line continues the paragraph before it, instead of starting another block.
+fn is_lazy_continuation_line(input_lines: &[&str], index: usize) -> bool {
+    use super::code_blocks::get_opening_fence;
+    use super::headings::hash_headings::validation::validate_hash_heading;
+    use super::html_blocks::get_html_block_end;
+    use super::lists::can_interrupt_paragraph;
+    use super::math_blocks::get_math_block_end;
+    use super::thematic_breaks::is_thematic_break;
+
+    let line = input_lines[index];
+
+    !line.trim().is_empty()
+        && !validate_hash_heading(line)
+        && !is_thematic_break(line)
+        && !can_interrupt_paragraph(line)
+        && get_opening_fence(input_lines, index).is_none()
+        && get_math_block_end(input_lines, index).is_none()
+        && get_html_block_end(input_lines, index, Some(line)).is_none()
+}
+
+/// Returns the prefix of a blockquote line, including a space after the marker.
+/// (ex: `> ` from `> Quote`)
+/// Lines without a marker have no prefix.
+pub fn get_blockquote_prefix(line: &str) -> &str {
+    if !is_blockquote_line(line) {
+        return "";
+    }
+
+    let marker_index = line.find('>').map_or(0, |index| index + 1);
+    let prefix_length = if line[marker_index..].starts_with([' ', '\t']) {
+        marker_index + 1
+    } else {
+        marker_index
+    };
+
+    &line[..prefix_length]
+}
+
+/// Removes blockquote markers (`>`) from the start of a line.
+pub fn strip_blockquote_markers(line: &str) -> &str {
+    let mut rest = line;
+
+    loop {
+        let trimmed = rest.trim_start_matches(' ');
+        if rest.len() - trimmed.len() > 3 || !trimmed.starts_with('>') {
+            return rest;
+        }
+
+        rest = &trimmed[1..];
+        rest = rest.strip_prefix(' ').unwrap_or(rest);
+    }
+}
+
+/// Removes a level of blockquote markers from a line.
+pub fn strip_blockquote_prefix(line: &str) -> &str {
+    &line[get_blockquote_prefix(line).len()..]
+}
+
+/// Checks if the first line of a blockquote starts a callout. (ex: `[!note] Title`)
+pub fn is_callout(first_line: &str) -> bool {
+    first_line
+        .trim_start()
+        .strip_prefix("[!")
+        .and_then(|rest| rest.find(']'))
+        .is_some_and(|length| length > 0)
+}
@@ -0,0 +1,134 @@
+use super::blockquotes::strip_blockquote_markers;
+
+/// The opening fence of a code block.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CodeFence {
+    /// Either '`' or '~'.
+    pub character: char,
+    /// The number of fence characters.
+    pub length: usize,
+    /// The column where the fence starts, after blockquote markers.
+    pub indent: usize,
+    /// Code blocks in list items are a part of the list.
+    pub is_in_list_item: bool,
+}
+
+/// Returns the code fence a line opens with.
+/// A fence is a sequence of at least three backticks or tildes.
+///
+/// Fences can be indented up to three spaces, and can be nested in blockquotes
+/// and list items.
+pub fn get_opening_fence(input_lines: &[&str], index: usize) -> Option<CodeFence> {
+    let line = strip_blockquote_markers(input_lines[index]);
+    let rest = strip_list_markers(line);
+    let indent = line.len() - rest.trim_start().len();
+    let rest = rest.trim_start();
+
+    let character = rest.chars().next()?;
+    if character != '`' && character != '~' {
+        return None;
+    }
+
+    let length = rest.chars().take_while(|&c| c == character).count();
+    if length < 3 {
+        return None;
+    }
+
+    // Info strings of backtick fences cannot contain backticks.
+    if character == '`' && rest[length..].contains('`') {
+        return None;
+    }
+
+    // Lines indented four or more spaces are indented code, unless they are in a list item.
+    let is_list_item_line = rest.len() != line.trim_start().len();
+    let is_in_list_item = is_list_item_line || is_in_list_item(input_lines, index, indent);
+    if indent > 3 && !is_in_list_item {
+        return None;
+    }
+
+    Some(CodeFence {
+        character,
+        length,
+        indent,
+        is_in_list_item,
+    })
+}
+
+/// Checks whether a line closes a code block, following the CommonMark rules.
+///
+/// A closing fence uses the same character as the opening fence,
+/// is at least as long as it, and has no info string.
+/// Only trailing spaces are allowed after it.
+pub fn is_closing_fence(line: &str, opening_fence: &CodeFence) -> bool {
+    let line = strip_blockquote_markers(line);
+    let rest = line.trim_start_matches(' ');
+    let indent = line.len() - rest.len();
+    if indent > opening_fence.indent + 3 {
+        return false;
+    }
+
+    let length = rest
+        .chars()
+        .take_while(|&c| c == opening_fence.character)
+        .count();
+
+    length >= opening_fence.length && rest[length..].trim_end().is_empty()
+}
+
+/// Removes list markers (`-`, `*`, `+`, `1.` or `1)`) from the start of a line.
+fn strip_list_markers(line: &str) -> &str {
+    let mut rest = line;
+
+    while let Some(content_column) = get_list_item_content_column(rest) {
+        rest = &rest[content_column..];
+    }
+
+    rest
+}
+
+/// Returns the column where the content of a list item starts.
+fn get_list_item_content_column(line: &str) -> Option<usize> {
+    let trimmed = line.trim_start_matches(' ');
+    let indent = line.len() - trimmed.len();
+
+    let marker_length = if trimmed.starts_with(['-', '*', '+'].as_ref()) {
+        1
+    } else {
+        let digit_count = trimmed.chars().take_while(char::is_ascii_digit).count();
+        if !(1..=9).contains(&digit_count)
+            || !trimmed[digit_count..].starts_with(['.', ')'].as_ref())
+        {
+            return None;
+        }
+        digit_count + 1
+    };
+
+    let after_marker = &trimmed[marker_length..];
+    let spaces = after_marker.len() - after_marker.trim_start_matches(' ').len();
+    if spaces == 0 {
+        return None;
+    }
+
+    Some(indent + marker_length + spaces)
+}
+
+/// Checks whether an indented line belongs to a list item above it.
+pub fn is_in_list_item(input_lines: &[&str], index: usize, indent: usize) -> bool {
+    for &line in input_lines[..index].iter().rev() {
+        let line = strip_blockquote_markers(line);
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        if let Some(content_column) = get_list_item_content_column(line) {
+            if content_column <= indent && indent <= content_column + 3 {
+                return true;
+            }
+        } else if !line.starts_with(' ') {
+            // A line that is not indented ends the list.
+            return false;
+        }
+    }
+
+    false
+}
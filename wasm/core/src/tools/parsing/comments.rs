@@ -0,0 +1,68 @@
+/// The syntax of a comment.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CommentKind {
+    /// `%% ... %%`
+    Obsidian,
+    /// `<!-- ... -->`
+    Html,
+}
+
+impl CommentKind {
+    fn opening_marker(&self) -> &'static str {
+        match self {
+            CommentKind::Obsidian => "%%",
+            CommentKind::Html => "<!--",
+        }
+    }
+
+    fn closing_marker(&self) -> &'static str {
+        match self {
+            CommentKind::Obsidian => "%%",
+            CommentKind::Html => "-->",
+        }
+    }
+}
+
+/// Returns the kind of comment a line opens with.
+pub fn get_opening_comment(line: &str) -> Option<CommentKind> {
+    let line = line.trim_start();
+
+    if line.starts_with(CommentKind::Obsidian.opening_marker()) {
+        Some(CommentKind::Obsidian)
+    } else if line.starts_with(CommentKind::Html.opening_marker()) {
+        Some(CommentKind::Html)
+    } else {
+        None
+    }
+}
+
+/// Returns the comment that is still open at the end of a line.
+/// `open_comment` is the comment that is open at the start of the line.
+///
+/// Markers are read from left to right, so a line can close a comment and open another one.
+/// (ex: `%% Comment %% Text %%` leaves a comment open)
+pub fn get_unclosed_comment(line: &str, open_comment: Option<CommentKind>) -> Option<CommentKind> {
+    let mut open_comment = open_comment;
+    let mut rest = line;
+
+    loop {
+        match open_comment {
+            Some(kind) => {
+                let marker = kind.closing_marker();
+                match rest.find(marker) {
+                    Some(marker_index) => rest = &rest[marker_index + marker.len()..],
+                    None => return open_comment,
+                }
+                open_comment = None;
+            }
+            None => {
+                let (marker_index, kind) = [CommentKind::Obsidian, CommentKind::Html]
+                    .iter()
+                    .filter_map(|&kind| Some((rest.find(kind.opening_marker())?, kind)))
+                    .min_by_key(|&(marker_index, _)| marker_index)?;
+                rest = &rest[marker_index + kind.opening_marker().len()..];
+                open_comment = Some(kind);
+            }
+        }
+    }
+}
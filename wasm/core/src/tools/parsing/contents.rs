@@ -0,0 +1,149 @@
+use super::super::tokens::MarkdownSection;
+use super::lists::{can_interrupt_paragraph, is_list_item};
+use super::tables::is_table_start;
+use super::thematic_breaks::is_thematic_break;
+
+/// Finishes the current "content" section and pushes it into the "sections" vector.
+/// Lists, tables and thematic breaks in the content become their own sections.
+pub fn finish_current_content_section(
+    is_reading_content_section: &mut bool,
+    sections: &mut Vec<MarkdownSection>,
+    temp_content_section: &mut String,
+) {
+    *is_reading_content_section = false;
+
+    // Check if the current "content" is empty.
+    // Because this function will also be called with empty values.
+    if temp_content_section.is_empty() {
+        return;
+    }
+
+    sections.extend(split_content_section(temp_content_section.trim_end()));
+    temp_content_section.clear();
+}
+
+/// Appends a line with a line break.
+pub fn append_a_line_break(string: &mut String, line: &str) {
+    if !string.is_empty() {
+        string.push('\n');
+    }
+
+    string.push_str(line);
+}
+
+/// Splits a content section into paragraphs, lists, tables and thematic breaks.
+fn split_content_section(content: &str) -> Vec<MarkdownSection> {
+    let lines: Vec<&str> = content.split('\n').collect();
+
+    let mut sections: Vec<MarkdownSection> = Vec::new();
+    let mut paragraph_lines: Vec<&str> = Vec::new();
+
+    let mut index = 0;
+    while index < lines.len() {
+        let line = lines[index];
+        let is_in_paragraph = paragraph_lines
+            .last()
+            .is_some_and(|line| !line.trim().is_empty());
+
+        // Read thematic breaks.
+        // A line of `-` right after a paragraph is a setext heading underline instead.
+        let is_underline = is_in_paragraph && line.trim().chars().all(|c| c == '-');
+        if is_thematic_break(line) && !is_underline {
+            finish_paragraph(&mut sections, &mut paragraph_lines);
+            sections.push(MarkdownSection::ThematicBreak(line.to_string()));
+
+            index += 1;
+            continue;
+        }
+
+        // Read tables. They end at a blank line.
+        if !is_in_paragraph && is_table_start(&lines, index) {
+            let end = lines[index..]
+                .iter()
+                .position(|line| line.trim().is_empty())
+                .map_or(lines.len(), |offset| index + offset);
+
+            finish_paragraph(&mut sections, &mut paragraph_lines);
+            sections.push(MarkdownSection::Table(lines[index..end].join("\n")));
+
+            index = end;
+            continue;
+        }
+
+        // Read lists.
+        if is_list_item(line) && (!is_in_paragraph || can_interrupt_paragraph(line)) {
+            let end = get_list_end(&lines, index);
+
+            finish_paragraph(&mut sections, &mut paragraph_lines);
+            sections.push(MarkdownSection::List(lines[index..end].join("\n")));
+
+            index = end;
+            continue;
+        }
+
+        paragraph_lines.push(line);
+        index += 1;
+    }
+    finish_paragraph(&mut sections, &mut paragraph_lines);
+
+    sections
+}
+
+/// Pushes the lines of a paragraph as a content section, without surrounding blank lines.
+fn finish_paragraph(sections: &mut Vec<MarkdownSection>, paragraph_lines: &mut Vec<&str>) {
+    let paragraph = paragraph_lines
+        .iter()
+        .skip_while(|line| line.trim().is_empty())
+        .copied()
+        .collect::<Vec<&str>>()
+        .join("\n");
+    let paragraph = paragraph.trim_end();
+
+    if !paragraph.is_empty() {
+        sections.push(MarkdownSection::Content(paragraph.to_string()));
+    }
+    paragraph_lines.clear();
+}
+
+/// Returns the index of the line after a list starting at `start`.
+///
+/// Lists continue with list items, indented lines, and lines right after
+/// non-blank list lines. (lazy continuation lines)
+pub fn get_list_end(lines: &[&str], start: usize) -> usize {
+    let mut end = start + 1;
+
+    let mut index = start + 1;
+    while index < lines.len() {
+        let line = lines[index];
+        let is_indented = line.starts_with([' ', '\t']);
+
+        // Blank lines are a part of the list only if the list continues after them.
+        if line.trim().is_empty() {
+            let next_line = lines[index..].iter().find(|line| !line.trim().is_empty());
+            let is_list_continued = next_line.is_some_and(|next_line| {
+                next_line.starts_with([' ', '\t'])
+                    || (is_list_item(next_line) && !is_thematic_break(next_line))
+            });
+
+            if !is_list_continued {
+                break;
+            }
+            index += 1;
+            continue;
+        }
+
+        if is_thematic_break(line) && !is_indented {
+            break;
+        }
+
+        let is_lazy_continuation_line = !lines[index - 1].trim().is_empty();
+        if !is_list_item(line) && !is_indented && !is_lazy_continuation_line {
+            break;
+        }
+
+        index += 1;
+        end = index;
+    }
+
+    end
+}
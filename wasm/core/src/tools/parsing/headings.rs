@@ -0,0 +1,364 @@
+/// Gets the top heading level of the entire document.
+/// Hashes without a space are headings too, if `reads_unspaced_hash_headings` is true.
+pub fn get_top_heading_level(
+    input_lines: &[&str],
+    reads_unspaced_hash_headings: bool,
+) -> Option<usize> {
+    use self::alternate_headings::get_valid_alternate_heading_level;
+    use self::alternate_headings::validation::validate_alternate_heading_title;
+    use self::hash_headings::{get_hash_heading_level, validation::validate_any_hash_heading_line};
+    use super::blockquotes::get_blockquote_end;
+    use super::code_blocks::{get_opening_fence, is_closing_fence, CodeFence};
+    use super::comments::{get_opening_comment, get_unclosed_comment, CommentKind};
+    use super::html_blocks::get_html_block_end;
+    use super::ignores::get_ignored_region_end;
+    use super::math_blocks::get_math_block_end;
+    use super::properties::get_properties_end;
+
+    let mut top_heading_level = usize::MAX;
+
+    let mut reading_code_block_fence: Option<CodeFence> = None;
+    let mut reading_comment_kind: Option<CommentKind> = None;
+    let mut reading_math_block_end: Option<usize> = None;
+    let mut reading_html_block_end: Option<usize> = None;
+    let mut reading_ignored_region_end: Option<usize> = None;
+    let mut reading_blockquote_end: Option<usize> = None;
+    // The last line that was read as content, which can be the title of an alternate heading.
+    let mut last_content_line_index: Option<usize> = None;
+
+    // Skip properties.
+    let properties_end = get_properties_end(input_lines);
+
+    for (index, &line) in input_lines.iter().enumerate() {
+        if properties_end.is_some_and(|properties_end| index <= properties_end) {
+            continue;
+        }
+
+        // Skip ignored regions.
+        if reading_ignored_region_end.is_none()
+            && reading_code_block_fence.is_none()
+            && reading_comment_kind.is_none()
+            && reading_math_block_end.is_none()
+            && reading_html_block_end.is_none()
+            && reading_blockquote_end.is_none()
+        {
+            reading_ignored_region_end = get_ignored_region_end(input_lines, index);
+        }
+        if let Some(ignored_region_end) = reading_ignored_region_end {
+            if index == ignored_region_end {
+                reading_ignored_region_end = None;
+            }
+            continue;
+        }
+
+        // Skip comments.
+        let is_opening_comment_line = reading_comment_kind.is_none();
+        if is_opening_comment_line
+            && reading_code_block_fence.is_none()
+            && reading_math_block_end.is_none()
+            && reading_html_block_end.is_none()
+            && reading_blockquote_end.is_none()
+        {
+            reading_comment_kind = get_opening_comment(line);
+        }
+        if let Some(comment_kind) = reading_comment_kind {
+            let open_comment = if is_opening_comment_line {
+                None
+            } else {
+                Some(comment_kind)
+            };
+            reading_comment_kind = get_unclosed_comment(line, open_comment);
+            continue;
+        }
+
+        // Skip blockquotes.
+        if reading_blockquote_end.is_none()
+            && reading_code_block_fence.is_none()
+            && reading_math_block_end.is_none()
+            && reading_html_block_end.is_none()
+        {
+            reading_blockquote_end = get_blockquote_end(input_lines, index);
+        }
+        if let Some(blockquote_end) = reading_blockquote_end {
+            if index == blockquote_end {
+                reading_blockquote_end = None;
+            }
+            continue;
+        }
+
+        // Skip code blocks.
+        if let Some(fence) = reading_code_block_fence {
+            if is_closing_fence(line, &fence) {
+                // Exit a code block.
+                reading_code_block_fence = None;
+            }
+            if fence.is_in_list_item {
+                last_content_line_index = Some(index);
+            }
+            continue;
+        }
+        if let Some(fence) = get_opening_fence(input_lines, index)
+            .filter(|_| reading_math_block_end.is_none() && reading_html_block_end.is_none())
+        {
+            // Enter a code block. Code blocks in list items are a part of the list.
+            reading_code_block_fence = Some(fence);
+            if fence.is_in_list_item {
+                last_content_line_index = Some(index);
+            }
+            continue;
+        }
+
+        // Skip math blocks.
+        if reading_math_block_end.is_none() {
+            reading_math_block_end = get_math_block_end(input_lines, index);
+        }
+        if let Some(math_block_end) = reading_math_block_end {
+            if index == math_block_end {
+                reading_math_block_end = None;
+            }
+            continue;
+        }
+
+        // Skip HTML blocks.
+        if reading_html_block_end.is_none() {
+            let last_content_line = last_content_line_index
+                .filter(|&last_content_line_index| last_content_line_index + 1 == index)
+                .map(|last_content_line_index| input_lines[last_content_line_index]);
+            reading_html_block_end = get_html_block_end(input_lines, index, last_content_line);
+        }
+        if let Some(html_block_end) = reading_html_block_end {
+            if index == html_block_end {
+                reading_html_block_end = None;
+            }
+            continue;
+        }
+
+        // Parse hash headings.
+        let is_valid_hash_heading =
+            validate_any_hash_heading_line(input_lines, index, reads_unspaced_hash_headings);
+
+        if is_valid_hash_heading {
+            let heading_level = get_hash_heading_level(line);
+            if heading_level < top_heading_level {
+                top_heading_level = heading_level;
+            }
+
+            if heading_level == 1 {
+                break;
+            }
+        }
+
+        // Parse alternate headings.
+        let alternate_heading_level: Option<usize> =
+            get_valid_alternate_heading_level(input_lines, index).filter(|_| {
+                index > 0
+                    && last_content_line_index == Some(index - 1)
+                    && validate_alternate_heading_title(input_lines[index - 1])
+            });
+
+        if let Some(alternate_heading_level) = alternate_heading_level {
+            if alternate_heading_level == 1 && 1 < top_heading_level {
+                top_heading_level = 1;
+            } else if alternate_heading_level == 2 && 2 < top_heading_level {
+                top_heading_level = 2;
+            }
+        } else if !is_valid_hash_heading {
+            last_content_line_index = Some(index);
+            // Skip comments that are opened in the middle of a content line.
+            reading_comment_kind = get_unclosed_comment(line, None);
+        }
+    }
+
+    if top_heading_level == usize::MAX {
+        return None;
+    }
+
+    Some(top_heading_level)
+}
+
+pub mod hash_headings {
+    /// Counts the hashes of a hash heading.
+    pub fn get_hash_heading_level(line: &str) -> usize {
+        line.trim_start().chars().take_while(|&c| c == '#').count()
+    }
+
+    pub mod validation {
+        /// Validates hash heading syntax.
+        /// Headings can be indented up to three spaces.
+        /// Hashes are followed by a space, or end the line. (ex: `#tag` is not a heading)
+        pub fn validate_hash_heading(line: &str) -> bool {
+            let text = line.trim_start_matches(' ');
+            if line.len() - text.len() > 3 {
+                return false;
+            }
+
+            let level = super::get_hash_heading_level(text);
+            let rest = &text[level..];
+            level > 0 && (rest.is_empty() || rest.starts_with([' ', '\t']))
+        }
+
+        /// Validates hash heading syntax without a space after the hashes. (ex: `##Heading`)
+        /// They are read as headings only when heading syntax is normalized.
+        pub fn validate_unspaced_hash_heading(line: &str) -> bool {
+            let text = line.trim_start_matches(' ');
+            if line.len() - text.len() > 3 {
+                return false;
+            }
+
+            let level = super::get_hash_heading_level(text);
+            let rest = &text[level..];
+            (1..=6).contains(&level) && rest.starts_with(|c: char| !c.is_whitespace())
+        }
+
+        /// Validates hash heading syntax, with the lines around it.
+        /// Indented lines under a list item belong to the item.
+        pub fn validate_hash_heading_line(input_lines: &[&str], index: usize) -> bool {
+            let line = input_lines[index];
+            validate_hash_heading(line) && !is_indented_in_list_item(input_lines, index)
+        }
+
+        /// Validates hash heading syntax, with the lines around it.
+        /// Hashes without a space are also read when `reads_unspaced_hash_headings` is true.
+        pub fn validate_any_hash_heading_line(
+            input_lines: &[&str],
+            index: usize,
+            reads_unspaced_hash_headings: bool,
+        ) -> bool {
+            let line = input_lines[index];
+            let is_hash_heading = validate_hash_heading(line)
+                || (reads_unspaced_hash_headings && validate_unspaced_hash_heading(line));
+            is_hash_heading && !is_indented_in_list_item(input_lines, index)
+        }
+
+        /// Checks if an indented line belongs to a list item.
+        fn is_indented_in_list_item(input_lines: &[&str], index: usize) -> bool {
+            let line = input_lines[index];
+            let indent = line.len() - line.trim_start().len();
+            indent > 0
+                && crate::tools::parsing::code_blocks::is_in_list_item(input_lines, index, indent)
+        }
+    }
+}
+
+pub mod alternate_headings {
+    /// Checks a valid alternate heading syntax and returns its level.
+    pub fn get_valid_alternate_heading_level(
+        input_lines: &[&str],
+        reading_index: usize,
+    ) -> Option<usize> {
+        use validation::get_valid_alternate_top_heading_level::get_alternate_heading_level;
+        use validation::validate_previous_alternate_headings;
+
+        if reading_index > input_lines.len() - 1 {
+            return None;
+        }
+        if input_lines[reading_index].is_empty()
+            || !input_lines[reading_index]
+                .chars()
+                .all(|char| char == '-' || char == '=')
+        {
+            return None;
+        }
+
+        let heading_level = validate_previous_alternate_headings(input_lines, reading_index);
+        if !heading_level {
+            return None;
+        }
+
+        get_alternate_heading_level(input_lines[reading_index])
+    }
+
+    pub mod validation {
+        pub fn validate_previous_alternate_headings(
+            input_lines: &[&str],
+            reading_index: usize,
+        ) -> bool {
+            use self::get_valid_alternate_top_heading_level::get_alternate_heading_level;
+
+            let mut is_reading_syntax = true;
+            let mut is_reading_title = false;
+
+            for (index, &line) in input_lines[0..=reading_index].iter().enumerate().rev() {
+                if line.is_empty() && is_reading_syntax && !is_reading_title {
+                    return true;
+                }
+
+                if is_reading_syntax {
+                    if super::super::hash_headings::validation::validate_hash_heading_line(
+                        input_lines,
+                        index,
+                    ) {
+                        return true;
+                    }
+
+                    if get_alternate_heading_level(line).is_some() {
+                        is_reading_syntax = false;
+                        is_reading_title = true;
+                        continue;
+                    }
+
+                    return false;
+                } else if is_reading_title {
+                    if super::super::hash_headings::validation::validate_hash_heading_line(
+                        input_lines,
+                        index,
+                    ) {
+                        return false;
+                    }
+                    if index == 0 {
+                        return true;
+                    }
+
+                    if !line.is_empty() {
+                        is_reading_syntax = true;
+                        is_reading_title = false;
+                        continue;
+                    }
+
+                    return false;
+                }
+            }
+
+            false // Fallback.
+        }
+
+        pub mod get_valid_alternate_top_heading_level {
+            /// Checks which level of alternate heading is being read.
+            /// EXAMPLE: heading-1 or heading-2
+            pub fn get_alternate_heading_level(line: &str) -> Option<usize> {
+                if line.is_empty() {
+                    return None;
+                }
+
+                let valid_alternate_heading_1 = line.chars().all(|char| char == '=');
+                let valid_alternate_heading_2 = line.chars().all(|char| char == '-');
+
+                if valid_alternate_heading_1 {
+                    Some(1)
+                } else if valid_alternate_heading_2 {
+                    Some(2)
+                } else {
+                    None
+                }
+            }
+        }
+
+        /// Validates the title line of an alternate heading.
+        /// Lines that belong to other Markdown blocks cannot be titles.
+        pub fn validate_alternate_heading_title(line: &str) -> bool {
+            use crate::tools::parsing::{
+                blockquotes::is_blockquote_line, code_blocks::get_opening_fence,
+                lists::is_list_item, thematic_breaks::is_thematic_break,
+            };
+
+            !line.trim().is_empty()
+                && !line.starts_with("    ")
+                && get_opening_fence(&[line], 0).is_none()
+                && !line.starts_with('\t')
+                && !super::super::hash_headings::validation::validate_hash_heading(line)
+                && !is_list_item(line)
+                && !is_blockquote_line(line)
+                && !is_thematic_break(line)
+        }
+    }
+}
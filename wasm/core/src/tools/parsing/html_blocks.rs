@@ -0,0 +1,251 @@
+/// Tag names that start an HTML block, even if the tag is not complete.
+const BLOCK_TAG_NAMES: [&str; 62] = [
+    "address",
+    "article",
+    "aside",
+    "base",
+    "basefont",
+    "blockquote",
+    "body",
+    "caption",
+    "center",
+    "col",
+    "colgroup",
+    "dd",
+    "details",
+    "dialog",
+    "dir",
+    "div",
+    "dl",
+    "dt",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "frame",
+    "frameset",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "head",
+    "header",
+    "hr",
+    "html",
+    "iframe",
+    "legend",
+    "li",
+    "link",
+    "main",
+    "menu",
+    "menuitem",
+    "nav",
+    "noframes",
+    "ol",
+    "optgroup",
+    "option",
+    "p",
+    "param",
+    "search",
+    "section",
+    "summary",
+    "table",
+    "tbody",
+    "td",
+    "tfoot",
+    "th",
+    "thead",
+    "title",
+    "tr",
+    "track",
+    "ul",
+];
+
+/// Tag names whose contents can have blank lines.
+const RAW_TEXT_TAG_NAMES: [&str; 4] = ["pre", "script", "style", "textarea"];
+
+/// How an HTML block ends.
+enum HtmlBlockEnd {
+    /// The block ends at a line containing the string.
+    Marker(&'static str),
+    /// The block ends before a blank line.
+    BlankLine,
+}
+
+/// Returns the index of the last line of an HTML block starting at `index`.
+/// Returns `None` if the line does not start an HTML block.
+///
+/// This follows the seven start conditions of CommonMark HTML blocks.
+/// Blocks that are never closed end at the end of the document.
+/// `last_content_line` is the previous line, if it was read as content.
+/// Complete tags of other elements cannot interrupt a paragraph.
+pub fn get_html_block_end(
+    input_lines: &[&str],
+    index: usize,
+    last_content_line: Option<&str>,
+) -> Option<usize> {
+    let is_in_paragraph = last_content_line.is_some_and(|line| !line.trim().is_empty());
+    let line = input_lines[index];
+    let indent = line.len() - line.trim_start_matches(' ').len();
+    if indent > 3 {
+        return None;
+    }
+
+    let block_end = get_html_block_start(&line[indent..], is_in_paragraph)?;
+    let last_index = input_lines.len() - 1;
+
+    match block_end {
+        HtmlBlockEnd::Marker(marker) => {
+            // The end marker can be in the starting line.
+            let end = input_lines[index..]
+                .iter()
+                .position(|line| line.to_ascii_lowercase().contains(marker))
+                .map_or(last_index, |offset| index + offset);
+            Some(end)
+        }
+        HtmlBlockEnd::BlankLine => {
+            let end = input_lines[index + 1..]
+                .iter()
+                .position(|line| line.trim().is_empty())
+                .map_or(last_index, |offset| index + offset);
+            Some(end)
+        }
+    }
+}
+
+/// Checks the start conditions of HTML blocks.
+fn get_html_block_start(line: &str, is_in_paragraph: bool) -> Option<HtmlBlockEnd> {
+    if !line.starts_with('<') {
+        return None;
+    }
+    let lowercase_line = line.to_ascii_lowercase();
+
+    // 1. Raw text elements.
+    for (tag_name, end_marker) in
+        RAW_TEXT_TAG_NAMES
+            .iter()
+            .zip(["</pre>", "</script>", "</style>", "</textarea>"])
+    {
+        if let Some(rest) = lowercase_line[1..].strip_prefix(tag_name) {
+            if rest.is_empty() || rest.starts_with([' ', '\t', '>']) {
+                return Some(HtmlBlockEnd::Marker(end_marker));
+            }
+        }
+    }
+
+    // 2. Comments. (These are usually read as comment sections first.)
+    if line.starts_with("<!--") {
+        return Some(HtmlBlockEnd::Marker("-->"));
+    }
+
+    // 3. Processing instructions.
+    if line.starts_with("<?") {
+        return Some(HtmlBlockEnd::Marker("?>"));
+    }
+
+    // 5. CDATA sections.
+    if line.starts_with("<![CDATA[") {
+        return Some(HtmlBlockEnd::Marker("]]>"));
+    }
+
+    // 4. Declarations.
+    if line[1..].starts_with('!') && line[2..].starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return Some(HtmlBlockEnd::Marker(">"));
+    }
+
+    // 6. Block elements.
+    let tag = lowercase_line[1..]
+        .strip_prefix('/')
+        .unwrap_or(&lowercase_line[1..]);
+    let tag_name_length = tag
+        .find(|c: char| !c.is_ascii_alphanumeric())
+        .unwrap_or(tag.len());
+    let rest = &tag[tag_name_length..];
+    if BLOCK_TAG_NAMES.contains(&&tag[..tag_name_length])
+        && (rest.is_empty() || rest.starts_with([' ', '\t', '>']) || rest.starts_with("/>"))
+    {
+        return Some(HtmlBlockEnd::BlankLine);
+    }
+
+    // 7. Other complete tags on their own line.
+    if !is_in_paragraph {
+        let tag_length = get_open_tag_length(line).or_else(|| get_closing_tag_length(line))?;
+        if line[tag_length..].trim().is_empty() {
+            return Some(HtmlBlockEnd::BlankLine);
+        }
+    }
+
+    None
+}
+
+/// Returns the length of a tag name at the start of the input.
+fn get_tag_name_length(input: &str) -> Option<usize> {
+    if !input.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return None;
+    }
+
+    Some(
+        input
+            .find(|c: char| !c.is_ascii_alphanumeric() && c != '-')
+            .unwrap_or(input.len()),
+    )
+}
+
+/// Returns the length of an open tag at the start of the input. (ex: `<a href="url">`)
+fn get_open_tag_length(input: &str) -> Option<usize> {
+    let tag_name_length = get_tag_name_length(input.strip_prefix('<')?)?;
+    if RAW_TEXT_TAG_NAMES.contains(&input[1..1 + tag_name_length].to_ascii_lowercase().as_str()) {
+        return None;
+    }
+    let mut rest = &input[1 + tag_name_length..];
+
+    // Read attributes.
+    loop {
+        let trimmed = rest.trim_start();
+        let has_whitespace = trimmed.len() < rest.len();
+        let attribute_name_length = trimmed
+            .find(|c: char| !(c.is_ascii_alphanumeric() || "_.:-".contains(c)))
+            .unwrap_or(trimmed.len());
+        let is_attribute_name =
+            trimmed.starts_with(|c: char| c.is_ascii_alphabetic() || "_:".contains(c));
+        if !has_whitespace || !is_attribute_name {
+            rest = trimmed;
+            break;
+        }
+        rest = &trimmed[attribute_name_length..];
+
+        // Read an attribute value.
+        let value_specification = rest.trim_start();
+        if let Some(value) = value_specification.strip_prefix('=') {
+            let value = value.trim_start();
+            let value_length = match value.chars().next()? {
+                quote @ ('"' | '\'') => value[1..].find(quote)? + 2,
+                _ => value
+                    .find(|c: char| c.is_whitespace() || "\"'=<>`".contains(c))
+                    .unwrap_or(value.len()),
+            };
+            if value_length == 0 {
+                return None;
+            }
+            rest = &value[value_length..];
+        }
+    }
+
+    let rest = rest.strip_prefix('/').unwrap_or(rest);
+    rest.strip_prefix('>')?;
+
+    Some(input.len() - rest.len() + 1)
+}
+
+/// Returns the length of a closing tag at the start of the input. (ex: `</a>`)
+fn get_closing_tag_length(input: &str) -> Option<usize> {
+    let tag_name = input.strip_prefix("</")?;
+    let tag_name_length = get_tag_name_length(tag_name)?;
+    let rest = tag_name[tag_name_length..].trim_start();
+    rest.strip_prefix('>')?;
+
+    Some(input.len() - rest.len() + 1)
+}
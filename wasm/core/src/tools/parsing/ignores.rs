@@ -0,0 +1,104 @@
+use super::blockquotes::get_blockquote_end;
+use super::code_blocks::{get_opening_fence, is_closing_fence};
+use super::contents::get_list_end;
+use super::headings::hash_headings::validation::validate_hash_heading_line;
+use super::html_blocks::get_html_block_end;
+use super::lists::is_list_item;
+use super::math_blocks::get_math_block_end;
+
+/// Directives that keep parts of a document as they are written.
+/// They are written in comments. (ex: `<!-- formatto-ignore -->` or `%% formatto-ignore %%`)
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum IgnoreDirective {
+    /// `formatto-ignore-start`
+    Start,
+    /// `formatto-ignore-end`
+    End,
+    /// `formatto-ignore`: Ignores the next section.
+    Next,
+}
+
+/// Reads a directive from a line that only has a comment.
+fn get_ignore_directive(line: &str) -> Option<IgnoreDirective> {
+    let line = line.trim();
+    let name = line
+        .strip_prefix("<!--")
+        .and_then(|line| line.strip_suffix("-->"))
+        .or_else(|| {
+            line.strip_prefix("%%")
+                .and_then(|line| line.strip_suffix("%%"))
+        })?;
+
+    match name.trim() {
+        "formatto-ignore-start" => Some(IgnoreDirective::Start),
+        "formatto-ignore-end" => Some(IgnoreDirective::End),
+        "formatto-ignore" => Some(IgnoreDirective::Next),
+        _ => None,
+    }
+}
+
+/// Returns the last line of an ignored region that starts at a line.
+/// Returns `None` if the line does not start an ignored region.
+///
+/// Regions without `formatto-ignore-end` continue until the end of the document.
+pub fn get_ignored_region_end(input_lines: &[&str], index: usize) -> Option<usize> {
+    match get_ignore_directive(input_lines[index])? {
+        IgnoreDirective::Start => Some(
+            input_lines[index + 1..]
+                .iter()
+                .position(|&line| get_ignore_directive(line) == Some(IgnoreDirective::End))
+                .map_or(input_lines.len() - 1, |offset| index + 1 + offset),
+        ),
+        IgnoreDirective::End => None,
+        IgnoreDirective::Next => {
+            Some(get_next_section_end(input_lines, index + 1).unwrap_or(index))
+        }
+    }
+}
+
+/// Returns the last line of the first section at or after a line.
+fn get_next_section_end(input_lines: &[&str], index: usize) -> Option<usize> {
+    let start = index
+        + input_lines
+            .get(index..)?
+            .iter()
+            .position(|line| !line.trim().is_empty())?;
+
+    if let Some(fence) =
+        get_opening_fence(input_lines, start).filter(|fence| !fence.is_in_list_item)
+    {
+        return Some(
+            input_lines[start + 1..]
+                .iter()
+                .position(|line| is_closing_fence(line, &fence))
+                .map_or(input_lines.len() - 1, |offset| start + 1 + offset),
+        );
+    }
+    if let Some(end) = get_math_block_end(input_lines, start)
+        .or_else(|| get_html_block_end(input_lines, start, None))
+        .or_else(|| get_blockquote_end(input_lines, start))
+    {
+        return Some(end);
+    }
+    if validate_hash_heading_line(input_lines, start) {
+        return Some(start);
+    }
+    if is_list_item(input_lines[start]) {
+        // Headings cannot be lazy continuation lines of lists.
+        let list_end = get_list_end(input_lines, start);
+        let heading_start =
+            (start + 1..list_end).find(|&index| validate_hash_heading_line(input_lines, index));
+
+        return Some(heading_start.unwrap_or(list_end) - 1);
+    }
+
+    // Other sections end before a blank line.
+    Some(
+        start
+            + input_lines[start..]
+                .iter()
+                .take_while(|line| !line.trim().is_empty())
+                .count()
+            - 1,
+    )
+}
@@ -0,0 +1,48 @@
+/// A list item marker. (ex: `-`, `1.`)
+struct ListMarker<'a> {
+    /// The number of an ordered list item.
+    number: Option<u64>,
+    /// Text after the marker.
+    rest: &'a str,
+}
+
+/// Reads the list item marker a line starts with.
+fn get_list_marker(line: &str) -> Option<ListMarker<'_>> {
+    let indent = line.len() - line.trim_start_matches(' ').len();
+    if indent > 3 {
+        return None;
+    }
+    let line = &line[indent..];
+
+    let (number, rest) = if line.starts_with(['-', '+', '*']) {
+        (None, &line[1..])
+    } else {
+        let digit_count = line.chars().take_while(|c| c.is_ascii_digit()).count();
+        if digit_count == 0 || digit_count > 9 || !line[digit_count..].starts_with(['.', ')']) {
+            return None;
+        }
+        (line[..digit_count].parse().ok(), &line[digit_count + 1..])
+    };
+
+    // Markers are followed by a space, unless the item is empty.
+    if !rest.is_empty() && !rest.starts_with([' ', '\t']) {
+        return None;
+    }
+
+    Some(ListMarker { number, rest })
+}
+
+/// Checks if a line starts a list item. (ex: `- Item`, `1. Item`)
+pub fn is_list_item(line: &str) -> bool {
+    get_list_marker(line).is_some()
+}
+
+/// Checks if a list item can start a list right after a paragraph line.
+/// Otherwise, it would be a part of the paragraph.
+///
+/// Only non-empty items can, and ordered lists have to start with 1.
+pub fn can_interrupt_paragraph(line: &str) -> bool {
+    get_list_marker(line).is_some_and(|marker| {
+        !marker.rest.trim().is_empty() && marker.number.is_none_or(|number| number == 1)
+    })
+}
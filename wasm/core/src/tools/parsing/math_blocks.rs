@@ -0,0 +1,20 @@
+/// Returns the index of the line that closes a math block opening at `index`.
+/// Returns `None` if the line does not open a math block, or the block is never closed.
+///
+/// A math block starts with `$$` and ends with a line ending with `$$`.
+/// It can also open and close on the same line. (ex: `$$ e^{i\pi} + 1 = 0 $$`)
+pub fn get_math_block_end(input_lines: &[&str], index: usize) -> Option<usize> {
+    let line = input_lines[index].trim();
+    if !line.starts_with("$$") {
+        return None;
+    }
+
+    if line.len() >= 4 && line.ends_with("$$") {
+        return Some(index);
+    }
+
+    input_lines[index + 1..]
+        .iter()
+        .position(|line| line.trim_end().ends_with("$$"))
+        .map(|offset| index + 1 + offset)
+}
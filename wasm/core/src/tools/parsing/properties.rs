@@ -0,0 +1,71 @@
+/// Fences of property sections that start and end with the same line.
+/// - `---`: YAML
+/// - `+++`: TOML
+/// - `;;;`: JSON
+const PROPERTY_FENCES: [&str; 3] = ["---", "+++", ";;;"];
+
+/// Returns the index of the closing line of the property section of a document.
+/// Returns `None` if the document does not start with a property section.
+///
+/// Property sections start at the first line, and end with a closing line.
+/// Without the closing line, the first line is not a property section.
+pub fn get_properties_end(input_lines: &[&str]) -> Option<usize> {
+    // Lines of documents with CRLF line breaks end with `\r`.
+    let first_line = input_lines.first()?.trim_end();
+
+    if PROPERTY_FENCES.contains(&first_line) {
+        return input_lines[1..]
+            .iter()
+            .position(|line| line.trim_end() == first_line)
+            .map(|offset| offset + 1);
+    }
+
+    if first_line.starts_with('{') {
+        // Other text can start with braces too. (ex: `{{date}}` of templates)
+        let end = get_json_object_end(input_lines)?;
+        let json_object = input_lines[..=end].join("\n");
+        return serde_json::from_str::<serde_json::Map<String, serde_json::Value>>(&json_object)
+            .ok()
+            .map(|_| end);
+    }
+
+    None
+}
+
+/// Returns the index of the line where a JSON object at the first line is closed.
+/// Nothing but whitespace can follow the closing brace.
+fn get_json_object_end(input_lines: &[&str]) -> Option<usize> {
+    let mut depth = 0;
+    let mut is_in_string = false;
+    let mut is_escaped = false;
+
+    for (index, line) in input_lines.iter().enumerate() {
+        for (ch_index, char) in line.char_indices() {
+            if is_in_string {
+                if is_escaped {
+                    is_escaped = false;
+                } else if char == '\\' {
+                    is_escaped = true;
+                } else if char == '"' {
+                    is_in_string = false;
+                }
+                continue;
+            }
+
+            match char {
+                '"' => is_in_string = true,
+                '{' | '[' => depth += 1,
+                '}' | ']' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        let is_object_end = char == '}' && line[ch_index + 1..].trim().is_empty();
+                        return if is_object_end { Some(index) } else { None };
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    None
+}
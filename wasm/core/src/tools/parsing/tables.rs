@@ -0,0 +1,49 @@
+/// Splits a table row into cells.
+fn get_cells(line: &str) -> Vec<&str> {
+    let line = line.trim();
+    let line = line.strip_prefix('|').unwrap_or(line);
+    let line = line.strip_suffix('|').unwrap_or(line);
+
+    let mut cells = Vec::new();
+    let mut cell_start = 0;
+    let mut is_escaped = false;
+    for (index, character) in line.char_indices() {
+        match character {
+            '\\' => is_escaped = !is_escaped,
+            '|' if !is_escaped => {
+                cells.push(&line[cell_start..index]);
+                cell_start = index + 1;
+            }
+            _ => is_escaped = false,
+        }
+    }
+    cells.push(&line[cell_start..]);
+
+    cells
+}
+
+/// Checks if a line is the delimiter row of a table. (ex: `| --- | :-: |`)
+fn is_delimiter_row(line: &str) -> bool {
+    line.contains('|')
+        && get_cells(line).iter().all(|cell| {
+            let cell = cell.trim();
+            let cell = cell.strip_prefix(':').unwrap_or(cell);
+            let cell = cell.strip_suffix(':').unwrap_or(cell);
+
+            !cell.is_empty() && cell.chars().all(|c| c == '-')
+        })
+}
+
+/// Checks if a table starts at a line.
+/// A table starts with a header row followed by a delimiter row with the same number of cells.
+pub fn is_table_start(lines: &[&str], index: usize) -> bool {
+    let header_row = lines[index];
+    let delimiter_row = match lines.get(index + 1) {
+        Some(delimiter_row) => delimiter_row,
+        None => return false,
+    };
+
+    header_row.contains('|')
+        && is_delimiter_row(delimiter_row)
+        && get_cells(header_row).len() == get_cells(delimiter_row).len()
+}
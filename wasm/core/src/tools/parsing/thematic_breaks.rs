@@ -0,0 +1,17 @@
+/// Checks if a line is a thematic break. (ex: `***`, `- - -`, `___`)
+pub fn is_thematic_break(line: &str) -> bool {
+    let indent = line.len() - line.trim_start_matches(' ').len();
+    if indent > 3 {
+        return false;
+    }
+
+    let rest = line.trim();
+    let character = match rest.chars().next() {
+        Some(character @ ('-' | '*' | '_')) => character,
+        _ => return false,
+    };
+
+    rest.chars()
+        .all(|c| c == character || c == ' ' || c == '\t')
+        && rest.chars().filter(|&c| c == character).count() >= 3
+}
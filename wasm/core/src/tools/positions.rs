@@ -0,0 +1,160 @@
+use serde::{Deserialize, Serialize};
+
+use crate::tools::parsing::blockquotes::{get_blockquote_prefix, strip_blockquote_prefix};
+use crate::tools::spans::{get_region_boundaries, split_lines};
+use crate::tools::tokens::{Blockquote, MarkdownSection};
+
+/// A position in a document.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+pub struct Position {
+    /// Zero-based line number.
+    pub line: usize,
+    /// Zero-based column in UTF-16 code units, as text editors count them.
+    pub ch: usize,
+}
+
+/// Maps positions in an input to where they end up in its formatted output.
+///
+/// Section lines are only moved, so positions in them keep their column.
+/// Positions in blockquotes are mapped through the sections inside them.
+/// Positions in a removed gap move to the start of the next section.
+pub fn get_mapped_positions(
+    input: &str,
+    output: &str,
+    sections: &[MarkdownSection],
+    positions: &[Position],
+) -> Vec<Position> {
+    let input_lines = split_lines(input);
+    let output_lines = split_lines(output);
+
+    map_line_positions(&input_lines, &output_lines, sections, positions)
+        .into_iter()
+        .map(|position| clamp_position(output, position))
+        .collect()
+}
+
+/// Maps positions between the lines of an input and its formatted output.
+fn map_line_positions(
+    input_lines: &[&str],
+    output_lines: &[&str],
+    sections: &[MarkdownSection],
+    positions: &[Position],
+) -> Vec<Position> {
+    let (input_boundaries, output_boundaries) =
+        get_region_boundaries(input_lines, output_lines, sections);
+
+    positions
+        .iter()
+        .map(|position| {
+            let region = input_boundaries
+                .windows(2)
+                .position(|region| region[0] <= position.line && position.line < region[1]);
+
+            match region {
+                Some(region) => {
+                    let input_start = input_boundaries[region];
+                    let line_in_region = position.line - input_start;
+                    let output_start = output_boundaries[region];
+                    let output_end = output_boundaries[region + 1];
+
+                    if region % 2 == 1 {
+                        if let MarkdownSection::Blockquote(blockquote) = &sections[region / 2] {
+                            let quoted_position = map_quoted_position(
+                                &input_lines[input_start..input_boundaries[region + 1]],
+                                &output_lines[output_start..output_end],
+                                blockquote,
+                                Position {
+                                    line: line_in_region,
+                                    ch: position.ch,
+                                },
+                            );
+
+                            return Position {
+                                line: output_start + quoted_position.line,
+                                ch: quoted_position.ch,
+                            };
+                        }
+                    }
+
+                    if output_start < output_end {
+                        // Other section lines are the same in both texts.
+                        Position {
+                            line: (output_start + line_in_region).min(output_end - 1),
+                            ch: position.ch,
+                        }
+                    } else {
+                        Position {
+                            line: output_start,
+                            ch: 0,
+                        }
+                    }
+                }
+                // Positions after the last line.
+                None => Position {
+                    line: usize::MAX,
+                    ch: usize::MAX,
+                },
+            }
+        })
+        .collect()
+}
+
+/// Maps a position in the lines of a blockquote, without their prefixes.
+fn map_quoted_position(
+    input_lines: &[&str],
+    output_lines: &[&str],
+    blockquote: &Blockquote,
+    position: Position,
+) -> Position {
+    let quoted_input: String = input_lines
+        .iter()
+        .map(|line| strip_blockquote_prefix(line))
+        .collect();
+    let quoted_output: String = output_lines
+        .iter()
+        .map(|line| strip_blockquote_prefix(line))
+        .collect();
+
+    let input_prefix_width = get_prefix_width(input_lines[position.line]);
+    let quoted_position = Position {
+        line: position.line,
+        ch: position.ch.saturating_sub(input_prefix_width),
+    };
+
+    let mapped_position = map_line_positions(
+        &split_lines(&quoted_input),
+        &split_lines(&quoted_output),
+        &blockquote.sections,
+        &[quoted_position],
+    )[0];
+    let line = mapped_position.line.min(output_lines.len() - 1);
+    let output_prefix_width = get_prefix_width(output_lines[line]);
+
+    // Positions in a prefix stay in the prefix.
+    let ch = if position.ch < input_prefix_width {
+        position.ch.min(output_prefix_width)
+    } else {
+        mapped_position.ch.saturating_add(output_prefix_width)
+    };
+
+    Position { line, ch }
+}
+
+/// Returns the width of the blockquote prefix of a line.
+fn get_prefix_width(line: &str) -> usize {
+    get_blockquote_prefix(line.trim_end_matches('\n'))
+        .encode_utf16()
+        .count()
+}
+
+/// Moves a position into the range of a text.
+fn clamp_position(text: &str, position: Position) -> Position {
+    let lines: Vec<&str> = text.split('\n').collect();
+    let line = position.line.min(lines.len() - 1);
+    let line_length = lines[line].encode_utf16().count();
+
+    Position {
+        line,
+        ch: position.ch.min(line_length),
+    }
+}
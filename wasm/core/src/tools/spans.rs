@@ -0,0 +1,162 @@
+use std::ops::Range;
+
+use crate::tools::{
+    parsing::{
+        blockquotes::{get_blockquote_end, is_blockquote_line},
+        headings::{
+            alternate_headings::validation::{
+                get_valid_alternate_top_heading_level::get_alternate_heading_level,
+                validate_alternate_heading_title,
+            },
+            hash_headings::validation::validate_hash_heading,
+        },
+        properties::get_properties_end,
+        thematic_breaks::is_thematic_break,
+    },
+    tokens::MarkdownSection,
+};
+
+/// Splits a text into lines, keeping their line breaks.
+pub fn split_lines(text: &str) -> Vec<&str> {
+    text.split_inclusive('\n').collect()
+}
+
+/// Finds the line range of each section in a text.
+///
+/// Sections keep their text as it was written, so each of them is a run of
+/// consecutive lines. Lines between sections are gaps.
+/// Returns `None` if a section cannot be found.
+pub fn get_section_spans(
+    lines: &[&str],
+    sections: &[MarkdownSection],
+) -> Option<Vec<Range<usize>>> {
+    let mut spans: Vec<Range<usize>> = Vec::with_capacity(sections.len());
+    let mut index = 0;
+
+    for section in sections {
+        let section_lines: Vec<&str> = section.text().split('\n').collect();
+        let first_line = section_lines[0];
+        let is_single_line = section_lines.len() == 1;
+        let is_first_section = spans.is_empty();
+
+        // Skip gap lines before the section.
+        loop {
+            let line = lines.get(index)?.trim_end_matches('\n');
+
+            // The parser trims the start of a document and the end of content sections.
+            let line = if is_first_section {
+                line.trim_start()
+            } else {
+                line
+            };
+            let is_section_start = if let MarkdownSection::Blockquote(_) = section {
+                is_blockquote_line(line)
+            } else if let MarkdownSection::ThematicBreak(_) = section {
+                // Thematic breaks can be rewritten with other characters.
+                is_thematic_break(line)
+            } else if let MarkdownSection::Heading(_) = section {
+                // Headings can be rewritten with hashes or underlines.
+                line.trim() == first_line.trim()
+                    || validate_hash_heading(line)
+                    || is_alternate_heading_title(lines, index)
+            } else if is_single_line {
+                line.trim_end() == first_line.trim_end()
+            } else {
+                line == first_line
+            };
+
+            if is_section_start {
+                break;
+            }
+            if !line.trim().is_empty() {
+                return None;
+            }
+            index += 1;
+        }
+
+        // Blockquotes are formatted inside, so their line counts can change.
+        let end = if let MarkdownSection::Blockquote(_) = section {
+            let remaining_lines: Vec<&str> = lines[index..]
+                .iter()
+                .map(|line| line.trim_end_matches('\n'))
+                .collect();
+            index + get_blockquote_end(&remaining_lines, 0)? + 1
+        } else if let MarkdownSection::Property(_) = section {
+            // Properties can be normalized, so their line counts can change too.
+            let remaining_lines: Vec<&str> = lines[index..]
+                .iter()
+                .map(|line| line.trim_end_matches('\n'))
+                .collect();
+            index + get_properties_end(&remaining_lines)? + 1
+        } else if let MarkdownSection::Heading(_) = section {
+            // Headings that are not rewritten keep their line counts. (ex: `##Heading` before `---`)
+            if lines[index].trim() == first_line.trim() {
+                index + section_lines.len()
+            } else if is_alternate_heading_title(lines, index) {
+                index + 2
+            } else {
+                index + 1
+            }
+        } else {
+            index + section_lines.len()
+        };
+        if end > lines.len() {
+            return None;
+        }
+
+        spans.push(index..end);
+        index = end;
+    }
+
+    Some(spans)
+}
+
+/// Checks if a line is the title of an alternate heading.
+/// Lines of other blocks are not titles. (ex: `# Heading` before `---`)
+fn is_alternate_heading_title(lines: &[&str], index: usize) -> bool {
+    validate_alternate_heading_title(lines[index].trim_end_matches('\n'))
+        && lines.get(index + 1).is_some_and(|next_line| {
+            get_alternate_heading_level(next_line.trim_end_matches('\n')).is_some()
+        })
+}
+
+/// Returns the start and end lines of every gap and section, in order,
+/// for both an input and its formatted output.
+///
+/// Regions with the same index match each other. Even regions are gaps,
+/// and odd regions are sections. If sections cannot be found,
+/// the entire document is a single region.
+/// That should not happen, so debug builds panic instead.
+pub fn get_region_boundaries(
+    input_lines: &[&str],
+    output_lines: &[&str],
+    sections: &[MarkdownSection],
+) -> (Vec<usize>, Vec<usize>) {
+    let spans = (
+        get_section_spans(input_lines, sections),
+        get_section_spans(output_lines, sections),
+    );
+    debug_assert!(
+        spans.0.is_some() && spans.1.is_some(),
+        "Sections cannot be found in the input or the output."
+    );
+
+    match spans {
+        (Some(input_spans), Some(output_spans)) => (
+            get_boundaries(&input_spans, input_lines.len()),
+            get_boundaries(&output_spans, output_lines.len()),
+        ),
+        _ => (vec![0, input_lines.len()], vec![0, output_lines.len()]),
+    }
+}
+
+fn get_boundaries(spans: &[Range<usize>], line_count: usize) -> Vec<usize> {
+    let mut boundaries = vec![0];
+    for span in spans {
+        boundaries.push(span.start);
+        boundaries.push(span.end);
+    }
+    boundaries.push(line_count);
+
+    boundaries
+}
@@ -0,0 +1,60 @@
+#[derive(Debug, Clone, PartialEq)]
+pub enum HeadingLevel {
+    Top(String),
+    FirstSub(String),
+    Sub(String),
+}
+
+/// A blockquote and the sections inside it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Blockquote {
+    /// The blockquote as it was written.
+    pub text: String,
+    /// The prefix of the first line, used for every formatted line. (ex: `> `)
+    pub prefix: String,
+    /// Obsidian callouts start with a type. (ex: `> [!note]`)
+    pub is_callout: bool,
+    /// Sections inside the blockquote, without prefixes.
+    pub sections: Vec<MarkdownSection>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MarkdownSection {
+    Property(String),
+    Heading(HeadingLevel),
+    Content(String),
+    Code(String),
+    Math(String),
+    Comment(String),
+    Html(String),
+    Blockquote(Blockquote),
+    List(String),
+    Table(String),
+    ThematicBreak(String),
+    /// A region that is kept as it is written. (ex: `<!-- formatto-ignore-start -->`)
+    Ignored(String),
+}
+
+impl MarkdownSection {
+    /// Returns the text of the section.
+    pub fn text(&self) -> &str {
+        match self {
+            MarkdownSection::Property(text)
+            | MarkdownSection::Content(text)
+            | MarkdownSection::Code(text)
+            | MarkdownSection::Math(text)
+            | MarkdownSection::Comment(text)
+            | MarkdownSection::Html(text)
+            | MarkdownSection::List(text)
+            | MarkdownSection::Table(text)
+            | MarkdownSection::ThematicBreak(text)
+            | MarkdownSection::Ignored(text) => text,
+            MarkdownSection::Blockquote(blockquote) => &blockquote.text,
+            MarkdownSection::Heading(heading_level) => match heading_level {
+                HeadingLevel::Top(text)
+                | HeadingLevel::FirstSub(text)
+                | HeadingLevel::Sub(text) => text,
+            },
+        }
+    }
+}
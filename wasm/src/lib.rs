@@ -1,9 +1,5 @@
-use std::error::Error;
-use utils::Preferences;
 use wasm_bindgen::prelude::*;
 
-mod option_schema;
-mod tools;
 mod utils;
 
 #[cfg(test)]
@@ -43,35 +39,135 @@ mod macro_rules {
 #[wasm_bindgen]
 /// This function will be called from the TypeScript side.
 pub fn format_document(input: &str, js_options: JsValue, js_locales: JsValue) -> String {
-    use utils::{read_js_value, read_options};
+    utils::set_panic_hook();
+
+    let (config, locales) = match utils::read_config(js_options, js_locales) {
+        Ok(config) => config,
+        Err(e) => {
+            let error_message = e.to_string();
+            wasm_bindgen::throw_str(&error_message);
+        }
+    };
 
+    // Return output to the TypeScript side or throw an error.
+    match formatto_core::format(input, &config) {
+        Ok(output) => output,
+        Err(e) => {
+            let error_message = utils::get_error_message(
+                &e,
+                config
+                    .options
+                    .other_options
+                    .show_more_detailed_error_messages,
+                &locales,
+            );
+            wasm_bindgen::throw_str(&error_message);
+        }
+    }
+}
+
+#[wasm_bindgen]
+/// Returns `true` if formatting would change the document.
+pub fn check_document(input: &str, js_options: JsValue, js_locales: JsValue) -> bool {
     utils::set_panic_hook();
 
-    let options = match read_options(js_options) {
-        Ok(options) => options,
+    let (config, locales) = match utils::read_config(js_options, js_locales) {
+        Ok(config) => config,
         Err(e) => {
             let error_message = e.to_string();
             wasm_bindgen::throw_str(&error_message);
         }
     };
 
-    let locales = match read_js_value(js_locales) {
-        Ok(locales) => locales,
+    match formatto_core::check(input, &config) {
+        Ok(changed) => changed,
+        Err(e) => {
+            let error_message = utils::get_error_message(
+                &e,
+                config
+                    .options
+                    .other_options
+                    .show_more_detailed_error_messages,
+                &locales,
+            );
+            wasm_bindgen::throw_str(&error_message);
+        }
+    }
+}
+
+#[wasm_bindgen]
+/// Returns line edits (`{ startLine, endLine, replacement }`) that format the document.
+pub fn format_document_edits(input: &str, js_options: JsValue, js_locales: JsValue) -> JsValue {
+    utils::set_panic_hook();
+
+    let (config, locales) = match utils::read_config(js_options, js_locales) {
+        Ok(config) => config,
         Err(e) => {
             let error_message = e.to_string();
             wasm_bindgen::throw_str(&error_message);
         }
     };
 
-    if input.is_empty() {
-        return input.to_string();
+    let edits = match formatto_core::format_edits(input, &config) {
+        Ok(edits) => edits,
+        Err(e) => {
+            let error_message = utils::get_error_message(
+                &e,
+                config
+                    .options
+                    .other_options
+                    .show_more_detailed_error_messages,
+                &locales,
+            );
+            wasm_bindgen::throw_str(&error_message);
+        }
+    };
+
+    match serde_wasm_bindgen::to_value(&edits) {
+        Ok(edits) => edits,
+        Err(e) => {
+            let error_message = e.to_string();
+            wasm_bindgen::throw_str(&error_message);
+        }
     }
+}
 
-    let preferences = Preferences { options, locales };
+#[wasm_bindgen]
+/// Returns line edits that format only the sections in lines `start_line..end_line`.
+pub fn format_document_range_edits(
+    input: &str,
+    js_options: JsValue,
+    js_locales: JsValue,
+    start_line: usize,
+    end_line: usize,
+) -> JsValue {
+    utils::set_panic_hook();
 
-    // Return output to the TypeScript side or throw an error.
-    match parse_input(input, &preferences) {
-        Ok(sections) => sections,
+    let (config, locales) = match utils::read_config(js_options, js_locales) {
+        Ok(config) => config,
+        Err(e) => {
+            let error_message = e.to_string();
+            wasm_bindgen::throw_str(&error_message);
+        }
+    };
+
+    let edits = match formatto_core::format_range_edits(input, &config, start_line..end_line) {
+        Ok(edits) => edits,
+        Err(e) => {
+            let error_message = utils::get_error_message(
+                &e,
+                config
+                    .options
+                    .other_options
+                    .show_more_detailed_error_messages,
+                &locales,
+            );
+            wasm_bindgen::throw_str(&error_message);
+        }
+    };
+
+    match serde_wasm_bindgen::to_value(&edits) {
+        Ok(edits) => edits,
         Err(e) => {
             let error_message = e.to_string();
             wasm_bindgen::throw_str(&error_message);
@@ -79,10 +175,54 @@ pub fn format_document(input: &str, js_options: JsValue, js_locales: JsValue) ->
     }
 }
 
-/// Parses an input and returns a formatted string.
-fn parse_input(input: &str, preferences: &Preferences) -> Result<String, Box<dyn Error>> {
-    let sections = tools::parsing::get_sections(input, preferences)?;
-    let output = tools::formatting::get_formatted_string(sections, preferences)?;
+#[wasm_bindgen]
+/// Returns where each position (`{ line, ch }`) ends up after formatting the document.
+pub fn map_document_positions(
+    input: &str,
+    js_options: JsValue,
+    js_locales: JsValue,
+    js_positions: JsValue,
+) -> JsValue {
+    use formatto_core::Position;
+
+    utils::set_panic_hook();
+
+    let (config, locales) = match utils::read_config(js_options, js_locales) {
+        Ok(config) => config,
+        Err(e) => {
+            let error_message = e.to_string();
+            wasm_bindgen::throw_str(&error_message);
+        }
+    };
+
+    let positions: Vec<Position> = match utils::read_options(js_positions) {
+        Ok(positions) => positions,
+        Err(e) => {
+            let error_message = e.to_string();
+            wasm_bindgen::throw_str(&error_message);
+        }
+    };
+
+    let mapped_positions = match formatto_core::map_positions(input, &config, &positions) {
+        Ok(mapped_positions) => mapped_positions,
+        Err(e) => {
+            let error_message = utils::get_error_message(
+                &e,
+                config
+                    .options
+                    .other_options
+                    .show_more_detailed_error_messages,
+                &locales,
+            );
+            wasm_bindgen::throw_str(&error_message);
+        }
+    };
 
-    Ok(output)
+    match serde_wasm_bindgen::to_value(&mapped_positions) {
+        Ok(mapped_positions) => mapped_positions,
+        Err(e) => {
+            let error_message = e.to_string();
+            wasm_bindgen::throw_str(&error_message);
+        }
+    }
 }
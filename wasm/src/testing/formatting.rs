@@ -1,7 +0,0 @@
-mod code_blocks;
-mod properties;
-
-mod headings {
-    mod alternate_headings;
-    mod hash_headings;
-}
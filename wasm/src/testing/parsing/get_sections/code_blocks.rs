@@ -1,92 +0,0 @@
-use crate::{
-    testing::{get_example_preferences, setup},
-    tools::{
-        parsing::get_sections,
-        tokens::{HeadingLevel, MarkdownSection},
-    },
-};
-
-#[test]
-fn case_1() {
-    setup();
-
-    let input = r#"## Heading 2
-Lorem Ipsum is simply dummy text of the printing and typesetting industry.
-
-#### Heading 4
-```rust
-fn main(
-println!(\"Hello World\");
-) {}
-```"#;
-
-    let expected_output = vec![
-        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
-        MarkdownSection::Content(
-            "Lorem Ipsum is simply dummy text of the printing and typesetting industry."
-                .to_string(),
-        ),
-        MarkdownSection::Heading(HeadingLevel::FirstSub("#### Heading 4".to_string())),
-        MarkdownSection::Code(
-            r#"```rust
-fn main(
-println!(\"Hello World\");
-) {}
-```"#
-                .to_string(),
-        ),
-    ];
-
-    assert_eq!(
-        get_sections(input, &get_example_preferences()).unwrap(),
-        expected_output
-    );
-}
-
-#[test]
-fn case_2() {
-    setup();
-
-    let input = r#"## Heading 2
-Lorem Ipsum is simply dummy text of the printing and typesetting industry.
-
-#### Heading 4
-`````rust
-fn main(
-println!(\"Hello World\");
-) {}
-`````"#;
-
-    let expected_output = vec![
-        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
-        MarkdownSection::Content(
-            "Lorem Ipsum is simply dummy text of the printing and typesetting industry."
-                .to_string(),
-        ),
-        MarkdownSection::Heading(HeadingLevel::FirstSub("#### Heading 4".to_string())),
-        MarkdownSection::Code(
-            r#"`````rust
-fn main(
-println!(\"Hello World\");
-) {}
-`````"#
-                .to_string(),
-        ),
-    ];
-
-    assert_eq!(
-        get_sections(input, &get_example_preferences()).unwrap(),
-        expected_output
-    );
-}
-
-#[test]
-fn invalid_input_1() {
-    setup();
-
-    let input = r#"```
-code
-SPACE```"#;
-    let sections = get_sections(input, &get_example_preferences());
-    assert!(sections.is_err());
-}
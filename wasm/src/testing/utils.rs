@@ -1 +1,2 @@
+mod get_error_message;
 mod get_locale_string;
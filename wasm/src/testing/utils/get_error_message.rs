@@ -0,0 +1,34 @@
+use super::super::get_example_locale;
+use crate::utils::get_error_message;
+use formatto_core::FormatError;
+
+#[test]
+fn invalid_option_1() {
+    let locales = get_example_locale();
+    let error = FormatError::InvalidOption {
+        field: "headingStyle".to_string(),
+        value: "underline".to_string(),
+    };
+
+    let left = get_error_message(&error, false, &locales);
+    let right = String::from(
+        "설정을 읽지 못했습니다. headingStyle의 값이 올바르지 않습니다: \"underline\"",
+    );
+
+    assert_eq!(left, right);
+}
+
+#[test]
+fn invalid_option_2() {
+    // Messages without a translation are written in English.
+    let error = FormatError::InvalidOption {
+        field: "formatto".to_string(),
+        value: "off-ish".to_string(),
+    };
+
+    let left = get_error_message(&error, false, &serde_json::Value::Null);
+    let right =
+        String::from("Failed to read options. The value of formatto is not valid: \"off-ish\"");
+
+    assert_eq!(left, right);
+}
@@ -1,3 +0,0 @@
-pub mod formatting;
-pub mod parsing;
-pub mod tokens;
@@ -2,7 +2,7 @@ use serde_json::Value;
 use std::error::Error;
 use wasm_bindgen::JsValue;
 
-use crate::option_schema::PluginOptions;
+use formatto_core::{FormatConfig, FormatError, PluginOptions};
 
 // To use the feature, use the following argument.
 // "--features development"
@@ -19,13 +19,6 @@ pub fn set_panic_hook() {
     console_error_panic_hook::set_once();
 }
 
-/// Struct for data from the TypeScript side.
-#[derive(Debug)]
-pub struct Preferences {
-    pub options: PluginOptions,
-    pub locales: Value,
-}
-
 /// Reads the plugin option data.
 pub fn read_options<T: serde::de::DeserializeOwned>(input: JsValue) -> Result<T, Box<dyn Error>> {
     Ok(serde_wasm_bindgen::from_value(input)?)
@@ -40,6 +33,19 @@ pub fn read_js_value(js_value: JsValue) -> Result<Value, Box<dyn Error>> {
     }
 }
 
+/// Reads options and locales from the TypeScript side.
+/// Options are validated here, so formatting does not fail because of them.
+pub fn read_config(
+    js_options: JsValue,
+    js_locales: JsValue,
+) -> Result<(FormatConfig, Value), Box<dyn Error>> {
+    let locales = read_js_value(js_locales)?;
+    let options = PluginOptions::from_value(&read_options(js_options)?)
+        .map_err(|e| get_error_message(&e, false, &locales))?;
+
+    Ok((FormatConfig::new(options), locales))
+}
+
 /// Message categories.
 pub enum LocaleCategory {
     Parsing,
@@ -67,3 +73,36 @@ pub fn get_locale_string(locales: &Value, category: LocaleCategory, key: &str) -
     }
     String::from(key)
 }
+
+/// Returns an error message in the user's language.
+pub fn get_error_message(
+    error: &FormatError,
+    show_more_detailed_error_messages: bool,
+    locales: &Value,
+) -> String {
+    match error {
+        FormatError::UnclosedCodeBlock { span } | FormatError::UnclosedComment { span } => {
+            if show_more_detailed_error_messages {
+                get_locale_string(
+                    locales,
+                    LocaleCategory::Parsing,
+                    "Failed to parse the document. [Line: {LINE_NUMBER}]",
+                )
+                .replace("{LINE_NUMBER}", &(span.start.line + 1).to_string())
+            } else {
+                get_locale_string(
+                    locales,
+                    LocaleCategory::Parsing,
+                    "Failed to parse the document.",
+                )
+            }
+        }
+        FormatError::InvalidOption { field, value } => get_locale_string(
+            locales,
+            LocaleCategory::Formatting,
+            "Failed to read options. The value of {FIELD} is not valid: \"{VALUE}\"",
+        )
+        .replace("{FIELD}", field)
+        .replace("{VALUE}", value),
+    }
+}